@@ -7,7 +7,7 @@ use libmedium::{
 fn main() {
     let hwmons = parse_hwmons().unwrap();
     for hwmon in &hwmons {
-        for (_, pwm) in hwmon.writeable_pwms() {
+        for pwm in hwmon.writeable_pwms().values() {
             pwm.write_enable(PwmEnable::ManualControl).unwrap();
             pwm.write_pwm(Pwm::FULLSPEED).unwrap();
         }
@@ -0,0 +1,84 @@
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter},
+};
+
+use crate::hwmon::Error as HwmonError;
+use crate::parsing::Error as ParsingError;
+use crate::sensors::Error as SensorError;
+use crate::units::Error as UnitError;
+
+/// Error that can hold any error returned by this crate.
+/// All of this crate's errors can be converted into it, so it can be used with `?` everywhere.
+#[derive(Debug)]
+pub enum Error {
+    /// Error parsing hwmons or sensors.
+    Parsing {
+        /// The source of the error.
+        source: ParsingError,
+    },
+
+    /// Error interacting with an hwmon.
+    Hwmon {
+        /// The source of the error.
+        source: HwmonError,
+    },
+
+    /// Error interacting with a sensor.
+    Sensor {
+        /// The source of the error.
+        source: SensorError,
+    },
+
+    /// Error converting units.
+    Unit {
+        /// The source of the error.
+        source: UnitError,
+    },
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Parsing { source } => Some(source),
+            Error::Hwmon { source } => Some(source),
+            Error::Sensor { source } => Some(source),
+            Error::Unit { source } => Some(source),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parsing { source } => write!(f, "Parsing error: {}", source),
+            Error::Hwmon { source } => write!(f, "Hwmon error: {}", source),
+            Error::Sensor { source } => write!(f, "Sensor error: {}", source),
+            Error::Unit { source } => write!(f, "Unit error: {}", source),
+        }
+    }
+}
+
+impl From<ParsingError> for Error {
+    fn from(source: ParsingError) -> Self {
+        Error::Parsing { source }
+    }
+}
+
+impl From<HwmonError> for Error {
+    fn from(source: HwmonError) -> Self {
+        Error::Hwmon { source }
+    }
+}
+
+impl From<SensorError> for Error {
+    fn from(source: SensorError) -> Self {
+        Error::Sensor { source }
+    }
+}
+
+impl From<UnitError> for Error {
+    fn from(source: UnitError) -> Self {
+        Error::Unit { source }
+    }
+}
@@ -1,6 +1,19 @@
 use super::*;
 
-use std::{collections::btree_map::Iter as BTreeIter, iter::FusedIterator};
+use crate::hwmon::device_watch::{scan_indices, DeviceWatcher, HwmonEvent};
+
+use futures_core::Stream;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, Interval, MissedTickBehavior};
+
+use std::{
+    collections::{btree_map::Iter as BTreeIter, BTreeSet},
+    future::Future,
+    iter::FusedIterator,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::Duration,
+};
 
 type InnerIter<'a> = BTreeIter<'a, u16, Hwmon>;
 
@@ -85,3 +98,56 @@ impl<'a, N: AsRef<str>> Iterator for NamedIter<'a, N> {
 }
 
 impl<'a, N: AsRef<str>> FusedIterator for NamedIter<'a, N> {}
+
+/// An endless stream that yields an event whenever a hwmon is added or removed.
+#[must_use = "streams are lazy and do nothing unless polled"]
+#[derive(Debug)]
+pub struct DeviceWatchStream {
+    watcher: DeviceWatcher,
+    interval: Interval,
+    scan: Option<JoinHandle<Option<BTreeSet<u16>>>>,
+}
+
+impl DeviceWatchStream {
+    pub(super) fn new(watcher: DeviceWatcher, poll: Duration) -> Self {
+        let mut interval = interval(poll);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        Self {
+            watcher,
+            interval,
+            scan: None,
+        }
+    }
+}
+
+impl Stream for DeviceWatchStream {
+    type Item = HwmonEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(event) = this.watcher.next_pending() {
+                return Poll::Ready(Some(event));
+            }
+
+            if let Some(scan) = &mut this.scan {
+                let current = ready!(Pin::new(scan).poll(cx));
+                this.scan = None;
+
+                // A panicked scan is treated like a failed one.
+                this.watcher.update(current.ok().flatten());
+                continue;
+            }
+
+            if this.interval.poll_tick(cx).is_pending() {
+                return Poll::Pending;
+            }
+
+            // Reading the directory blocks, so it must not run on the executor's threads.
+            let path = this.watcher.path().to_path_buf();
+            this.scan = Some(tokio::task::spawn_blocking(move || scan_indices(&path)));
+        }
+    }
+}
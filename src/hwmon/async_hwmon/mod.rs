@@ -3,27 +3,54 @@
 mod helper_functions;
 mod iterator;
 
+use super::chip_id::chip_id;
+#[cfg(feature = "writeable")]
+use super::config_script::{echo_line, SHEBANG};
+use super::device_kind::DeviceKind;
+use super::device_watch::DeviceWatcher;
 use super::error::{Error, Result};
+use super::parse_options::ParseOptions;
+#[cfg(feature = "writeable")]
+use super::pwm_fan_correlation::{perturbed_pwm, responding_fan};
+use super::read_options::ReadOptions;
+use super::sensor_counts::SensorCounts;
+use super::sensors_conf::{parse_labels, LabelOverride};
+use super::sensors_output::{adapter_name, display_rank, format_chip, format_reading, sort_key};
+use super::uevent::{parse_uevent, uevent_path, uevent_value};
+#[cfg(feature = "writeable")]
+use super::update_interval::{round_to_granularity, PROBE_INTERVAL};
 use helper_functions::*;
 
-pub use iterator::{Iter, NamedIter};
+pub use iterator::{DeviceWatchStream, Iter, NamedIter};
 
 use crate::parsing::{AsyncParseable, Error as ParsingError, Result as ParsingResult};
 use crate::sensors::async_sensors::{
-    curr::*, energy::*, fan::*, humidity::*, intrusion::*, power::*, pwm::*, temp::*, voltage::*,
+    any::AsyncAnySensor, curr::*, energy::*, fan::*, humidity::*, intrusion::*, power::*, pwm::*,
+    temp::*, voltage::*, AsyncDynSensor, AsyncSensor, DynSensorStruct,
 };
-use crate::units::Raw;
+use crate::sensors::{Error as SensorError, SensorKind, SensorSubFunctionType};
+
+#[cfg(feature = "writeable")]
+use crate::sensors::async_sensors::AsyncWriteableSensor;
+#[cfg(feature = "writeable")]
+use crate::sensors::optional;
+#[cfg(feature = "writeable")]
+use crate::units::{AngularVelocity, Pwm, PwmEnable};
+use crate::units::{Error as UnitError, Power, Raw, RawInteger, Temperature};
 
 use async_trait::async_trait;
+#[cfg(feature = "writeable")]
+use futures_util::future::join_all;
 
 use tokio::fs::read_to_string;
 
 use std::{
+    borrow::Cow,
     cmp::Ordering,
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fmt::Debug,
     io::ErrorKind as IoErrorKind,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
     time::Duration,
 };
 
@@ -42,6 +69,8 @@ pub struct Hwmon {
     pwms: BTreeMap<u16, PwmSensorStruct>,
     temps: BTreeMap<u16, TempSensorStruct>,
     voltages: BTreeMap<u16, VoltageSensorStruct>,
+    inaccessible_sensors: Vec<(String, PathBuf)>,
+    capabilities: Option<BTreeMap<(SensorKind, u16), Vec<SensorSubFunctionType>>>,
 }
 
 impl Hwmon {
@@ -60,6 +89,77 @@ impl Hwmon {
         self.index
     }
 
+    /// Returns the names and paths of all sensors that were skipped while parsing this hwmon,
+    /// because of insufficient rights.
+    pub fn inaccessible_sensors(&self) -> &[(String, PathBuf)] {
+        &self.inaccessible_sensors
+    }
+
+    /// Returns the supported read subfunctions of the sensor with the given kind and index.
+    /// If this hwmon was parsed with `precompute_caps`, the cached subfunctions are returned
+    /// without touching the filesystem. Otherwise they are probed on every call.
+    /// Returns `None`, if this hwmon has no such sensor.
+    pub fn capabilities(
+        &self,
+        kind: SensorKind,
+        index: u16,
+    ) -> Option<Cow<'_, [SensorSubFunctionType]>> {
+        fn probe<S: AsyncSensor>(
+            sensors: &BTreeMap<u16, S>,
+            index: u16,
+        ) -> Option<Vec<SensorSubFunctionType>> {
+            sensors
+                .get(&index)
+                .map(|sensor| sensor.supported_read_sub_functions())
+        }
+
+        if let Some(capabilities) = &self.capabilities {
+            return capabilities
+                .get(&(kind, index))
+                .map(|caps| Cow::Borrowed(caps.as_slice()));
+        }
+
+        let caps = match kind {
+            SensorKind::Current => probe(&self.currents, index),
+            SensorKind::Energy => probe(&self.energies, index),
+            SensorKind::Fan => probe(&self.fans, index),
+            SensorKind::Humidity => probe(&self.humidities, index),
+            SensorKind::Intrusion => probe(&self.intrusions, index),
+            SensorKind::Power => probe(&self.powers, index),
+            SensorKind::Pwm => probe(&self.pwms, index),
+            SensorKind::Temp => probe(&self.temps, index),
+            SensorKind::Voltage => probe(&self.voltages, index),
+        };
+
+        caps.map(Cow::Owned)
+    }
+
+    fn precompute_capabilities(&mut self) {
+        fn probe_all<S: AsyncSensor>(
+            kind: SensorKind,
+            sensors: &BTreeMap<u16, S>,
+            capabilities: &mut BTreeMap<(SensorKind, u16), Vec<SensorSubFunctionType>>,
+        ) {
+            for (&index, sensor) in sensors {
+                capabilities.insert((kind, index), sensor.supported_read_sub_functions());
+            }
+        }
+
+        let mut capabilities = BTreeMap::new();
+
+        probe_all(SensorKind::Current, &self.currents, &mut capabilities);
+        probe_all(SensorKind::Energy, &self.energies, &mut capabilities);
+        probe_all(SensorKind::Fan, &self.fans, &mut capabilities);
+        probe_all(SensorKind::Humidity, &self.humidities, &mut capabilities);
+        probe_all(SensorKind::Intrusion, &self.intrusions, &mut capabilities);
+        probe_all(SensorKind::Power, &self.powers, &mut capabilities);
+        probe_all(SensorKind::Pwm, &self.pwms, &mut capabilities);
+        probe_all(SensorKind::Temp, &self.temps, &mut capabilities);
+        probe_all(SensorKind::Voltage, &self.voltages, &mut capabilities);
+
+        self.capabilities = Some(capabilities);
+    }
+
     /// Returns this hwmon's device path.
     /// This path does not change between reboots.
     pub fn device_path(&self) -> PathBuf {
@@ -67,6 +167,132 @@ impl Hwmon {
         self.path().join("device").canonicalize().unwrap()
     }
 
+    /// Returns the name of the subsystem this hwmon's device belongs to like "pci" or "platform".
+    /// Returns `None`, if this hwmon has no device.
+    pub fn subsystem(&self) -> Option<String> {
+        self.path()
+            .join("device")
+            .join("subsystem")
+            .canonicalize()
+            .ok()?
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+    }
+
+    /// Returns the name of this hwmon's device, which is sometimes friendlier than the terse name
+    /// of the hwmon itself.
+    /// Returns `None`, if this hwmon has no device or the device has no name.
+    pub async fn device_name(&self) -> Option<String> {
+        let name = read_to_string(self.path().join("device").join("name"))
+            .await
+            .ok()?;
+        let name = name.trim();
+
+        (!name.is_empty()).then(|| name.to_string())
+    }
+
+    /// Returns the kind of device this hwmon belongs to, derived from its `subsystem`.
+    /// Hwmons without a device are classified as `DeviceKind::Virtual`.
+    pub fn device_kind(&self) -> DeviceKind {
+        let device_path = self.path().join("device").canonicalize().ok();
+
+        DeviceKind::classify(self.subsystem().as_deref(), device_path.as_deref())
+    }
+
+    /// Reads the uevent file of this hwmon's device and returns its key value pairs like
+    /// `DRIVER`, `MODALIAS` or `PCI_SLOT_NAME`.
+    /// This is a single read instead of following multiple symlinks.
+    /// Returns an error, if this hwmon has no device or the file can't be read.
+    pub async fn uevent(&self) -> Result<BTreeMap<String, String>> {
+        let path = uevent_path(self.path());
+
+        read_to_string(&path)
+            .await
+            .map(|content| parse_uevent(&content))
+            .map_err(|e| Error::io(e, path))
+    }
+
+    /// Returns the name of the kernel driver bound to this hwmon's device like "k10temp".
+    /// The name is taken from the device's uevent file if possible.
+    /// Returns `None`, if this hwmon has no device or no driver is bound to it.
+    pub fn driver(&self) -> Option<String> {
+        if let Some(driver) = uevent_value(self.path(), "DRIVER") {
+            return Some(driver);
+        }
+
+        self.path()
+            .join("device")
+            .join("driver")
+            .canonicalize()
+            .ok()?
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+    }
+
+    /// Returns the sysfs name of this hwmon's device which encodes its bus address like
+    /// "0000:03:00.0" for pci devices.
+    /// For pci devices the address is taken from the device's uevent file if possible.
+    /// Returns `None`, if this hwmon has no device.
+    pub fn bus_address(&self) -> Option<String> {
+        if let Some(slot) = uevent_value(self.path(), "PCI_SLOT_NAME") {
+            return Some(slot);
+        }
+
+        self.path()
+            .join("device")
+            .canonicalize()
+            .ok()?
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+    }
+
+    /// Reads the attribute at `rel` relative to this hwmon's device directory like `serial` or
+    /// `power1_cap`, since some attributes live on the device node rather than the hwmon node.
+    /// The content is returned with surrounding whitespace trimmed.
+    /// Symlinks are resolved and the attribute has to stay inside the device directory, so
+    /// attributes reached through links like `driver` or `subsystem` can't be read.
+    /// Returns an error, if `rel` is absolute, contains `..` or leaves the device directory, or if
+    /// the attribute can't be read.
+    pub async fn read_device_attr(&self, rel: &str) -> Result<String> {
+        let rel = Path::new(rel);
+
+        if !rel
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        {
+            return Err(Error::invalid_attribute_path(rel));
+        }
+
+        let device_path = self.path().join("device");
+        let device_dir = tokio::fs::canonicalize(&device_path)
+            .await
+            .map_err(|e| Error::io(e, device_path))?;
+        let path = device_dir.join(rel);
+        let path = tokio::fs::canonicalize(&path)
+            .await
+            .map_err(|e| Error::io(e, path))?;
+
+        if !path.starts_with(&device_dir) {
+            return Err(Error::invalid_attribute_path(rel));
+        }
+
+        read_to_string(&path)
+            .await
+            .map(|content| content.trim().to_string())
+            .map_err(|e| Error::io(e, path))
+    }
+
+    /// Returns the identifier libsensors and `sensors(1)` use for this hwmon like `coretemp-isa-0000`.
+    /// Falls back to `name-virtual-index`, if the bus of this hwmon's device is not known.
+    pub fn chip_id(&self) -> String {
+        chip_id(
+            self.name(),
+            self.subsystem().as_deref(),
+            self.bus_address().as_deref(),
+            self.index(),
+        )
+    }
+
     /// Returns this hwmon's update interval.
     /// If the hwmon does not expose the value, an error is returned.
     pub async fn update_interval(&self) -> Result<Duration> {
@@ -84,6 +310,23 @@ impl Hwmon {
         }
     }
 
+    /// Returns the rate at which this hwmon updates its sensor values in Hz.
+    /// This is useful for configuring poll loops and displaying the refresh rate.
+    /// If the hwmon does not expose its update interval or the interval is zero, an error is
+    /// returned.
+    pub async fn update_rate_hz(&self) -> Result<f64> {
+        let interval = self.update_interval().await?;
+
+        if interval.is_zero() {
+            return Err(Error::unit(
+                UnitError::invalid_value(0),
+                self.path().join("update_interval"),
+            ));
+        }
+
+        Ok(1.0 / interval.as_secs_f64())
+    }
+
     /// Returns whether this hwmon beeps if an alarm condition exists.
     /// If the hwmon does not expose the value, an error is returned.
     pub async fn beep_enable(&self) -> Result<bool> {
@@ -101,6 +344,249 @@ impl Hwmon {
         }
     }
 
+    /// Writes the current contents of all readable files of this hwmon into the given directory.
+    /// Together with `Hwmons::from_snapshot_archive` this can be used to reproduce this hwmon on
+    /// another system. Files that can't be read and links are skipped.
+    pub async fn dump_to_dir(&self, dir: impl AsRef<Path>) -> Result<()> {
+        let dir = dir.as_ref();
+
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| Error::io(e, dir))?;
+
+        let mut entries = tokio::fs::read_dir(self.path())
+            .await
+            .map_err(|e| Error::io(e, self.path()))?;
+
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| Error::io(e, self.path()))?
+        {
+            if !entry.file_type().await.is_ok_and(|t| t.is_file()) {
+                continue;
+            }
+
+            if let Ok(contents) = tokio::fs::read_to_string(entry.path()).await {
+                let target = dir.join(entry.file_name());
+
+                tokio::fs::write(&target, contents)
+                    .await
+                    .map_err(|e| Error::io(e, target))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads a type with a known layout from this hwmon.
+    /// See [`AsyncFromHwmon`] for details.
+    pub async fn read_named<T: AsyncFromHwmon>(&self) -> std::result::Result<T, T::Error> {
+        T::from_hwmon(self).await
+    }
+
+    /// Returns a clone of the sensor of the given kind with the given index.
+    /// Returns `None`, if no such sensor exists.
+    pub fn any_sensor(&self, kind: SensorKind, index: u16) -> Option<AsyncAnySensor> {
+        match kind {
+            SensorKind::Current => self.currents.get(&index).cloned().map(AsyncAnySensor::from),
+            SensorKind::Energy => self.energies.get(&index).cloned().map(AsyncAnySensor::from),
+            SensorKind::Fan => self.fans.get(&index).cloned().map(AsyncAnySensor::from),
+            SensorKind::Humidity => self
+                .humidities
+                .get(&index)
+                .cloned()
+                .map(AsyncAnySensor::from),
+            SensorKind::Intrusion => self
+                .intrusions
+                .get(&index)
+                .cloned()
+                .map(AsyncAnySensor::from),
+            SensorKind::Power => self.powers.get(&index).cloned().map(AsyncAnySensor::from),
+            SensorKind::Pwm => self.pwms.get(&index).cloned().map(AsyncAnySensor::from),
+            SensorKind::Temp => self.temps.get(&index).cloned().map(AsyncAnySensor::from),
+            SensorKind::Voltage => self.voltages.get(&index).cloned().map(AsyncAnySensor::from),
+        }
+    }
+
+    /// Returns clones of all sensors in this `Hwmon` whose name matches the given regular
+    /// expression, e.g. `^Core \d+$`. A sensor's name is its label if it has one.
+    /// Returns an error, if `pattern` is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    pub async fn sensors_matching(
+        &self,
+        pattern: &str,
+    ) -> std::result::Result<Vec<AsyncAnySensor>, regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        let mut matching = Vec::new();
+
+        for sensor in self.all_any_sensors() {
+            if regex.is_match(&sensor.name().await) {
+                matching.push(sensor);
+            }
+        }
+
+        Ok(matching)
+    }
+
+    /// Returns clones of all sensors in this `Hwmon` in the order `sensors(1)` lists them:
+    /// voltages, fans, temperatures, powers, energies, currents and humidities, followed by
+    /// intrusions and pwms. Sensors of the same kind are ordered by index.
+    pub fn display_order(&self) -> Vec<AsyncAnySensor> {
+        let mut sensors: Vec<AsyncAnySensor> = self.all_any_sensors().collect();
+        sensors.sort_by_key(|sensor| (display_rank(sensor.base()), sensor.index()));
+
+        sensors
+    }
+
+    /// Reads the name of every sensor in this `Hwmon` once and returns a map from name to
+    /// sensor, so repeated lookups by name don't touch sysfs again.
+    /// The name is the same as `name` returns: a label override, the label read from sysfs or,
+    /// for sensors without a label, a plain descriptor like `temp1`.
+    /// If multiple sensors share a name, the last one in the order of `display_order` wins.
+    pub async fn label_index(&self) -> BTreeMap<String, AsyncAnySensor> {
+        let mut index = BTreeMap::new();
+
+        for sensor in self.display_order() {
+            index.insert(sensor.name().await, sensor);
+        }
+
+        index
+    }
+
+    fn all_any_sensors(&self) -> impl Iterator<Item = AsyncAnySensor> + '_ {
+        let currents = self.currents.values().cloned().map(AsyncAnySensor::from);
+        let energies = self.energies.values().cloned().map(AsyncAnySensor::from);
+        let fans = self.fans.values().cloned().map(AsyncAnySensor::from);
+        let humidities = self.humidities.values().cloned().map(AsyncAnySensor::from);
+        let intrusions = self.intrusions.values().cloned().map(AsyncAnySensor::from);
+        let powers = self.powers.values().cloned().map(AsyncAnySensor::from);
+        let pwms = self.pwms.values().cloned().map(AsyncAnySensor::from);
+        let temps = self.temps.values().cloned().map(AsyncAnySensor::from);
+        let voltages = self.voltages.values().cloned().map(AsyncAnySensor::from);
+
+        currents
+            .chain(energies)
+            .chain(fans)
+            .chain(humidities)
+            .chain(intrusions)
+            .chain(powers)
+            .chain(pwms)
+            .chain(temps)
+            .chain(voltages)
+    }
+
+    /// Returns the base and index of every sensor in this `Hwmon` that has a beep subfunction,
+    /// so beep toggles can be offered only where they are supported.
+    pub fn beep_capable_sensors(&self) -> Vec<(String, u16)> {
+        fn beep_capable<S: AsyncSensor>(
+            sensors: &BTreeMap<u16, S>,
+            results: &mut Vec<(String, u16)>,
+        ) {
+            for sensor in sensors.values() {
+                if sensor
+                    .subfunction_path(SensorSubFunctionType::Beep)
+                    .exists()
+                {
+                    results.push((sensor.base().to_string(), sensor.index()));
+                }
+            }
+        }
+
+        let mut results = Vec::new();
+
+        beep_capable(&self.currents, &mut results);
+        beep_capable(&self.energies, &mut results);
+        beep_capable(&self.fans, &mut results);
+        beep_capable(&self.humidities, &mut results);
+        beep_capable(&self.intrusions, &mut results);
+        beep_capable(&self.powers, &mut results);
+        beep_capable(&self.pwms, &mut results);
+        beep_capable(&self.temps, &mut results);
+        beep_capable(&self.voltages, &mut results);
+
+        results
+    }
+
+    /// Returns the paths of all existing subfunction files of all sensors in this `Hwmon`.
+    /// This enumerates exactly the sensor files this crate might read or write, e.g. for auditing.
+    pub fn all_sensor_files(&self) -> Vec<PathBuf> {
+        fn present<S: AsyncSensor>(sensors: &BTreeMap<u16, S>, files: &mut Vec<PathBuf>) {
+            for sensor in sensors.values() {
+                files.extend(sensor.present_files());
+            }
+        }
+
+        let mut files = Vec::new();
+
+        present(&self.currents, &mut files);
+        present(&self.energies, &mut files);
+        present(&self.fans, &mut files);
+        present(&self.humidities, &mut files);
+        present(&self.intrusions, &mut files);
+        present(&self.powers, &mut files);
+        present(&self.pwms, &mut files);
+        present(&self.temps, &mut files);
+        present(&self.voltages, &mut files);
+
+        files
+    }
+
+    /// Reads every existing subfunction file of all sensors in this `Hwmon` and returns their
+    /// trimmed contents by path. This is the ideal artifact to attach to a bug report.
+    /// Files that can't be read for any reason, like write-only ones or ones the current user
+    /// lacks the rights for, are skipped. `ReadOptions` don't apply here.
+    pub async fn dump_raw(&self) -> BTreeMap<PathBuf, String> {
+        let mut dump = BTreeMap::new();
+
+        for path in self.all_sensor_files() {
+            if let Ok(raw) = read_to_string(&path).await {
+                dump.insert(path, raw.trim().to_string());
+            }
+        }
+
+        dump
+    }
+
+    /// Returns the number of sensors of each kind found in this `Hwmon`.
+    /// This doesn't read from the filesystem.
+    pub fn sensor_counts(&self) -> SensorCounts {
+        SensorCounts {
+            currents: self.currents.len(),
+            energies: self.energies.len(),
+            fans: self.fans.len(),
+            humidities: self.humidities.len(),
+            intrusions: self.intrusions.len(),
+            powers: self.powers.len(),
+            pwms: self.pwms.len(),
+            temps: self.temps.len(),
+            voltages: self.voltages.len(),
+        }
+    }
+
+    /// Returns all sensors found in this `Hwmon` as trait objects.
+    /// This is useful if you want to handle all sensors uniformly regardless of their kind.
+    pub fn all_dyn_sensors(&self) -> Vec<Box<dyn AsyncDynSensor>> {
+        fn boxed<S: AsyncSensor + Clone + Debug + Send + Sync + 'static>(
+            sensors: &BTreeMap<u16, S>,
+        ) -> impl Iterator<Item = Box<dyn AsyncDynSensor>> + '_ {
+            sensors
+                .values()
+                .map(|s| -> Box<dyn AsyncDynSensor> { Box::new(DynSensorStruct(s.clone())) })
+        }
+
+        boxed(&self.currents)
+            .chain(boxed(&self.energies))
+            .chain(boxed(&self.fans))
+            .chain(boxed(&self.humidities))
+            .chain(boxed(&self.intrusions))
+            .chain(boxed(&self.powers))
+            .chain(boxed(&self.pwms))
+            .chain(boxed(&self.temps))
+            .chain(boxed(&self.voltages))
+            .collect()
+    }
+
     /// Returns all current sensors found in this `Hwmon`.
     pub fn currents(&self) -> &BTreeMap<u16, impl AsyncCurrentSensor + Clone + Send + Sync> {
         &self.currents
@@ -200,13 +686,195 @@ impl Hwmon {
         self.temps.get(&index)
     }
 
+    /// Returns the first temp sensor whose name (its label, if it has one) equals the given label.
+    /// Returns `None`, if no sensor with the given label exists.
+    pub async fn temp_by_label(
+        &self,
+        label: &str,
+    ) -> Option<&(impl AsyncTempSensor + Clone + Send + Sync)> {
+        for temp in self.temps.values() {
+            if temp.name().await == label {
+                return Some(temp);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the temp sensor labeled "edge" as found on e.g. amdgpu.
+    /// Returns `None`, if no such sensor exists.
+    pub async fn temp_edge(&self) -> Option<&(impl AsyncTempSensor + Clone + Send + Sync)> {
+        self.temp_by_label("edge").await
+    }
+
+    /// Returns the temp sensor labeled "junction" as found on e.g. amdgpu.
+    /// Returns `None`, if no such sensor exists.
+    pub async fn temp_junction(&self) -> Option<&(impl AsyncTempSensor + Clone + Send + Sync)> {
+        self.temp_by_label("junction").await
+    }
+
+    /// Returns the temp sensor labeled "mem" as found on e.g. amdgpu.
+    /// Returns `None`, if no such sensor exists.
+    pub async fn temp_mem(&self) -> Option<&(impl AsyncTempSensor + Clone + Send + Sync)> {
+        self.temp_by_label("mem").await
+    }
+
+    /// Reads the inputs of all temp sensors in this hwmon and returns them by index.
+    /// With `skip_permission_errors` set in `options`, sensors that can't be read due to
+    /// insufficient rights are left out instead of failing the whole read.
+    /// Returns an error, if any other input can't be read.
+    pub async fn read_all_temps(
+        &self,
+        options: ReadOptions,
+    ) -> std::result::Result<BTreeMap<u16, Temperature>, SensorError> {
+        let mut temps = BTreeMap::new();
+
+        for (&index, temp) in &self.temps {
+            if let Some(input) = options.skippable(temp.read_input().await)? {
+                temps.insert(index, input);
+            }
+        }
+
+        Ok(temps)
+    }
+
+    /// Returns the mean of the inputs of all temp sensors in this hwmon that can be read.
+    /// Faulty sensors and sensors whose input can't be read are skipped.
+    /// Returns `None`, if no temp sensor could be read.
+    pub async fn average_temp(&self) -> Result<Option<Temperature>> {
+        let mut sum = 0i64;
+        let mut count = 0i64;
+
+        for temp in self.temps.values() {
+            if let Ok(input) = temp.read_input().await {
+                sum += input.to_raw_integer();
+                count += 1;
+            }
+        }
+
+        if count == 0 {
+            return Ok(None);
+        }
+
+        Temperature::try_from_raw_integer(sum / count)
+            .map(Some)
+            .map_err(|e| Error::unit(e, self.path()))
+    }
+
+    /// Computes the power drawn through the voltage sensor with index `volt_index` and the current
+    /// sensor with index `curr_index` by multiplying their inputs.
+    /// This is useful for boards that expose voltages and currents but no power sensors.
+    /// Returns `SubtypeNotSupported`, if either sensor doesn't exist or has no input.
+    /// The product of millivolts and milliamperes is computed in microwatts with integer math.
+    /// Returns an error, if the inputs can't be read or their product overflows or is not a valid
+    /// power, for example because the current is negative.
+    pub async fn derived_power(
+        &self,
+        volt_index: u16,
+        curr_index: u16,
+    ) -> std::result::Result<Power, SensorError> {
+        let not_supported = || SensorError::subtype_not_supported(SensorSubFunctionType::Input);
+
+        let voltage = self.voltages.get(&volt_index).ok_or_else(not_supported)?;
+        let current = self.currents.get(&curr_index).ok_or_else(not_supported)?;
+
+        let millivolts = voltage.read_input().await?.to_raw_integer();
+        let milliamperes = current.read_input().await?.to_raw_integer();
+
+        let microwatts = millivolts
+            .checked_mul(milliamperes)
+            .ok_or_else(|| UnitError::invalid_value(millivolts as f64 * milliamperes as f64))?;
+
+        Ok(Power::try_from_raw_integer(microwatts)?)
+    }
+
     /// Returns the voltage sensor with the given index.
     /// Returns `None`, if no sensor with the given index exists.
     pub fn voltage(&self, index: u16) -> Option<&(impl AsyncVoltageSensor + Clone + Send + Sync)> {
         self.voltages.get(&index)
     }
 
+    /// Sets whether the alarm subfunctions of all of this hwmon's sensors are active low, meaning
+    /// `0` signals an alarm. This is only needed for the few drivers that invert the polarity of
+    /// their alarms. All alarm readers of the sensors returned by this hwmon honor the polarity.
+    pub fn set_alarm_polarity(&mut self, active_low: bool) {
+        fn set<S: AsyncSensor>(sensors: &mut BTreeMap<u16, S>, active_low: bool) {
+            for sensor in sensors.values_mut() {
+                sensor.set_alarm_polarity(active_low);
+            }
+        }
+
+        set(&mut self.currents, active_low);
+        set(&mut self.energies, active_low);
+        set(&mut self.fans, active_low);
+        set(&mut self.humidities, active_low);
+        set(&mut self.intrusions, active_low);
+        set(&mut self.powers, active_low);
+        set(&mut self.pwms, active_low);
+        set(&mut self.temps, active_low);
+        set(&mut self.voltages, active_low);
+    }
+
+    /// Overrides the names of this hwmon's sensors with the labels found in the lm-sensors style
+    /// configuration file at `conf`, so that sensor names match the ones displayed by `sensors(1)`.
+    /// The supported grammar is a minimal subset of `sensors.conf(5)`:
+    /// `chip "pattern" ...` starts a section for all hwmons whose name matches the part of one of the
+    /// patterns before the first `-` (or `*` for all hwmons), `label feature "text"` overrides the name
+    /// of a sensor like `temp1` or `in0` in that section and `#` starts a comment.
+    /// All other statements are ignored.
+    pub async fn load_labels_from(&mut self, conf: impl AsRef<Path>) -> Result<()> {
+        let conf = conf.as_ref();
+        let contents = read_to_string(conf).await.map_err(|e| Error::io(e, conf))?;
+
+        self.apply_labels(&contents);
+
+        Ok(())
+    }
+
+    pub(crate) fn apply_labels(&mut self, conf: &str) {
+        for LabelOverride { base, index, label } in parse_labels(conf, &self.name) {
+            match base.as_str() {
+                "curr" => self.currents.get_mut(&index).map(|s| s.set_label(label)),
+                "energy" => self.energies.get_mut(&index).map(|s| s.set_label(label)),
+                "fan" => self.fans.get_mut(&index).map(|s| s.set_label(label)),
+                "humidity" => self.humidities.get_mut(&index).map(|s| s.set_label(label)),
+                "intrusion" => self.intrusions.get_mut(&index).map(|s| s.set_label(label)),
+                "power" => self.powers.get_mut(&index).map(|s| s.set_label(label)),
+                "pwm" => self.pwms.get_mut(&index).map(|s| s.set_label(label)),
+                "temp" => self.temps.get_mut(&index).map(|s| s.set_label(label)),
+                "in" => self.voltages.get_mut(&index).map(|s| s.set_label(label)),
+                _ => None,
+            };
+        }
+    }
+
+    /// Parses the hwmon at the given path, but only initializes sensors of the given kinds.
+    /// This saves a lot of file system accesses on chips with many channels, if you only care
+    /// about some kinds of sensors. All other kinds of sensors are left empty.
+    pub async fn parse_kinds(path: impl AsRef<Path>, kinds: &[SensorKind]) -> ParsingResult<Self> {
+        let path = path.as_ref();
+
+        let index = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default()
+            .strip_prefix("hwmon")
+            .unwrap_or_default()
+            .parse()
+            .map_err(|e| ParsingError::hwmon_index(e, path))?;
+
+        Self::try_from_path_with_kinds(path, index, kinds).await
+    }
+
     pub(crate) async fn try_from_path(path: impl Into<PathBuf>, index: u16) -> ParsingResult<Self> {
+        Self::try_from_path_with_kinds(path, index, SensorKind::all()).await
+    }
+
+    pub(crate) async fn try_from_path_with_kinds(
+        path: impl Into<PathBuf>,
+        index: u16,
+        kinds: &[SensorKind],
+    ) -> ParsingResult<Self> {
         let path = path.into();
 
         check_path(&path)?;
@@ -224,17 +892,47 @@ impl Hwmon {
             pwms: BTreeMap::new(),
             temps: BTreeMap::new(),
             voltages: BTreeMap::new(),
+            inaccessible_sensors: Vec::new(),
+            capabilities: None,
         };
 
-        hwmon.currents = init_sensors(&hwmon, 1).await?;
-        hwmon.energies = init_sensors(&hwmon, 1).await?;
-        hwmon.fans = init_sensors(&hwmon, 1).await?;
-        hwmon.humidities = init_sensors(&hwmon, 1).await?;
-        hwmon.intrusions = init_sensors(&hwmon, 0).await?;
-        hwmon.powers = init_sensors(&hwmon, 1).await?;
-        hwmon.pwms = init_sensors(&hwmon, 1).await?;
-        hwmon.temps = init_sensors(&hwmon, 1).await?;
-        hwmon.voltages = init_sensors(&hwmon, 0).await?;
+        let mut inaccessible = Vec::new();
+
+        for &kind in kinds {
+            let start_index = kind.start_index();
+
+            match kind {
+                SensorKind::Current => {
+                    hwmon.currents = init_sensors(&hwmon, start_index, &mut inaccessible).await?
+                }
+                SensorKind::Energy => {
+                    hwmon.energies = init_sensors(&hwmon, start_index, &mut inaccessible).await?
+                }
+                SensorKind::Fan => {
+                    hwmon.fans = init_sensors(&hwmon, start_index, &mut inaccessible).await?
+                }
+                SensorKind::Humidity => {
+                    hwmon.humidities = init_sensors(&hwmon, start_index, &mut inaccessible).await?
+                }
+                SensorKind::Intrusion => {
+                    hwmon.intrusions = init_sensors(&hwmon, start_index, &mut inaccessible).await?
+                }
+                SensorKind::Power => {
+                    hwmon.powers = init_sensors(&hwmon, start_index, &mut inaccessible).await?
+                }
+                SensorKind::Pwm => {
+                    hwmon.pwms = init_sensors(&hwmon, start_index, &mut inaccessible).await?
+                }
+                SensorKind::Temp => {
+                    hwmon.temps = init_sensors(&hwmon, start_index, &mut inaccessible).await?
+                }
+                SensorKind::Voltage => {
+                    hwmon.voltages = init_sensors(&hwmon, start_index, &mut inaccessible).await?
+                }
+            }
+        }
+
+        hwmon.inaccessible_sensors = inaccessible;
 
         Ok(hwmon)
     }
@@ -245,6 +943,42 @@ impl Hwmon {
     /// Set this hwmon's update interval.
     /// If the hwmon does not expose the value, an error is returned.
     pub async fn set_update_interval(&self, interval: Duration) -> Result<()> {
+        self.write_update_interval(interval).await
+    }
+
+    /// Set this hwmon's update interval rounded to the nearest multiple of `granularity`, because
+    /// some chips silently reject intervals they don't support.
+    /// The granularity can be found once with `update_interval_granularity` and reused.
+    /// If the hwmon does not expose the value, an error is returned.
+    pub async fn set_update_interval_rounded(
+        &self,
+        interval: Duration,
+        granularity: Duration,
+    ) -> Result<()> {
+        self.write_update_interval(round_to_granularity(interval, granularity))
+            .await
+    }
+
+    /// Infers the granularity of the update intervals this hwmon accepts by writing a probe
+    /// interval of 1ms and reading back the value the chip actually applied.
+    /// Strictly speaking this is the shortest interval the chip accepts, which only matches the
+    /// granularity for chips that accept multiples of a fixed step. It is meaningless for chips
+    /// that accept a few fixed intervals like 100ms, 250ms and 1s.
+    /// The chip briefly runs at the probed interval before the original one is restored, so call
+    /// this once and cache the result.
+    /// Returns an error, if the hwmon does not expose the value, the chip rejects the probe or
+    /// the original interval can't be restored.
+    pub async fn update_interval_granularity(&self) -> Result<Duration> {
+        let original = self.update_interval().await?;
+
+        self.write_update_interval(PROBE_INTERVAL).await?;
+        let probed = self.update_interval().await;
+        self.write_update_interval(original).await?;
+
+        Ok(probed?.max(PROBE_INTERVAL))
+    }
+
+    async fn write_update_interval(&self, interval: Duration) -> Result<()> {
         let path = self.path().join("update_interval");
 
         match tokio::fs::write(&path, interval.to_raw().as_bytes()).await {
@@ -257,6 +991,223 @@ impl Hwmon {
         }
     }
 
+    /// Resets the history of all sensors in this hwmon that support it.
+    /// Returns the base, index and result for every sensor whose history was reset.
+    pub async fn reset_all_history(
+        &self,
+    ) -> Vec<(String, u16, std::result::Result<(), SensorError>)> {
+        async fn reset<S: AsyncWriteableSensor>(
+            sensors: &BTreeMap<u16, S>,
+            results: &mut Vec<(String, u16, std::result::Result<(), SensorError>)>,
+        ) {
+            for sensor in sensors.values() {
+                if sensor.supports_reset_history() {
+                    results.push((
+                        sensor.base().to_string(),
+                        sensor.index(),
+                        sensor.reset_history().await,
+                    ));
+                }
+            }
+        }
+
+        let mut results = Vec::new();
+
+        reset(&self.currents, &mut results).await;
+        reset(&self.energies, &mut results).await;
+        reset(&self.fans, &mut results).await;
+        reset(&self.humidities, &mut results).await;
+        reset(&self.intrusions, &mut results).await;
+        reset(&self.powers, &mut results).await;
+        reset(&self.pwms, &mut results).await;
+        reset(&self.temps, &mut results).await;
+        reset(&self.voltages, &mut results).await;
+
+        results
+    }
+
+    /// Enables all sensors in this hwmon that have an enable subfunction by writing `1` to it.
+    /// Pwms are skipped, since writing `1` to their enable subfunction would switch them to manual control.
+    /// Returns the base, index and result for every sensor that was enabled.
+    pub async fn enable_all(&self) -> Vec<(String, u16, std::result::Result<(), SensorError>)> {
+        async fn enable<S: AsyncWriteableSensor>(
+            sensors: &BTreeMap<u16, S>,
+            results: &mut Vec<(String, u16, std::result::Result<(), SensorError>)>,
+        ) {
+            for sensor in sensors.values() {
+                if sensor
+                    .subfunction_path(SensorSubFunctionType::Enable)
+                    .exists()
+                {
+                    results.push((
+                        sensor.base().to_string(),
+                        sensor.index(),
+                        sensor
+                            .write_raw(SensorSubFunctionType::Enable, &true.to_raw())
+                            .await,
+                    ));
+                }
+            }
+        }
+
+        let mut results = Vec::new();
+
+        enable(&self.currents, &mut results).await;
+        enable(&self.energies, &mut results).await;
+        enable(&self.fans, &mut results).await;
+        enable(&self.humidities, &mut results).await;
+        enable(&self.intrusions, &mut results).await;
+        enable(&self.powers, &mut results).await;
+        enable(&self.temps, &mut results).await;
+        enable(&self.voltages, &mut results).await;
+
+        results
+    }
+
+    /// Experimentally determines which fan is driven by which pwm, for boards where the indices
+    /// of pwms and fans don't match.
+    /// Each pwm is switched to manual control and set to a noticeably different value for the
+    /// duration of `settle`, while the rpm of every fan is compared to its value before.
+    /// The fan whose rpm changes the most (by at least 100 rpm) is considered driven by the pwm.
+    /// Returns a map from pwm index to fan index. Pwms that no fan responds to are left out.
+    ///
+    /// # Warning
+    /// This is invasive: it takes over fan control and changes real fan speeds for up to
+    /// `settle` per pwm. Only call this while the system can tolerate altered cooling.
+    /// The original pwm values and enable states are restored afterwards, even if reading the
+    /// fans or one of the restoring writes fails. A crash or kill during the experiment leaves
+    /// the fans in the perturbed state.
+    /// Returns an error, if a fan can't be read or a pwm can't be perturbed or restored.
+    pub async fn correlate_pwm_fans(
+        &self,
+        settle: Duration,
+    ) -> std::result::Result<BTreeMap<u16, u16>, SensorError> {
+        let mut correlation = BTreeMap::new();
+
+        for pwm in self.pwms.values() {
+            let original_pwm = pwm.read_pwm().await?;
+            // The raw value is kept, because `PwmEnable` folds vendor specific automatic modes
+            // above 2 into `BiosControl`.
+            let original_enable = optional(pwm.read_enable_raw().await)?;
+            let baseline = self.fan_rpms().await?;
+
+            let perturbed = async {
+                if original_enable
+                    .is_some_and(|enable| enable.to_string() != PwmEnable::ManualControl.to_raw())
+                {
+                    pwm.write_enable(PwmEnable::ManualControl).await?;
+                }
+                pwm.write_pwm(perturbed_pwm(original_pwm)).await?;
+
+                tokio::time::sleep(settle).await;
+
+                self.fan_rpms().await
+            }
+            .await;
+
+            let restored = Self::restore_pwm(pwm, original_pwm, original_enable).await;
+            let perturbed = perturbed?;
+            restored?;
+
+            if let Some(fan) = responding_fan(&baseline, &perturbed) {
+                correlation.insert(pwm.index(), fan);
+            }
+        }
+
+        Ok(correlation)
+    }
+
+    /// Writes the given pwm values to the pwms with the corresponding indices concurrently.
+    /// Setting a whole fan profile this way doesn't wait for each write before starting the next.
+    /// Returns the result of every write by pwm index. Indices without a pwm yield a
+    /// `SubtypeNotSupported` error.
+    pub async fn write_all_pwms(
+        &self,
+        values: &BTreeMap<u16, Pwm>,
+    ) -> Vec<(u16, std::result::Result<(), SensorError>)> {
+        let writes = values.iter().map(|(&index, &value)| async move {
+            let result = match self.pwms.get(&index) {
+                Some(pwm) => pwm.write_pwm(value).await,
+                None => Err(SensorError::subtype_not_supported(
+                    SensorSubFunctionType::Pwm,
+                )),
+            };
+
+            (index, result)
+        });
+
+        join_all(writes).await
+    }
+
+    async fn fan_rpms(&self) -> std::result::Result<BTreeMap<u16, AngularVelocity>, SensorError> {
+        let mut rpms = BTreeMap::new();
+
+        for (index, fan) in &self.fans {
+            rpms.insert(*index, fan.read_input().await?);
+        }
+
+        Ok(rpms)
+    }
+
+    /// Writes back the original pwm value and raw enable value of a perturbed pwm.
+    /// Both writes are always attempted, the first error is returned.
+    async fn restore_pwm(
+        pwm: &PwmSensorStruct,
+        original_pwm: Pwm,
+        original_enable: Option<u8>,
+    ) -> std::result::Result<(), SensorError> {
+        let pwm_result = pwm.write_pwm(original_pwm).await;
+        let enable_result = match original_enable {
+            Some(enable) => {
+                pwm.write_raw(SensorSubFunctionType::Enable, &enable.to_string())
+                    .await
+            }
+            None => Ok(()),
+        };
+
+        pwm_result.and(enable_result)
+    }
+
+    /// Returns a shell script that restores the current value of every writeable subfunction of
+    /// every sensor in this hwmon, one `echo <value> > <path>` line each.
+    /// This can be used to snapshot a sensor configuration and replay it with a plain shell.
+    /// Values and paths are quoted, so paths containing spaces or quotes are safe.
+    /// Returns an error, if a supported subfunction can't be read.
+    pub async fn export_config_script(&self) -> std::result::Result<String, SensorError> {
+        async fn export<S: AsyncWriteableSensor>(
+            sensors: &BTreeMap<u16, S>,
+            lines: &mut Vec<String>,
+        ) -> std::result::Result<(), SensorError> {
+            for sensor in sensors.values() {
+                for &sub_type in SensorSubFunctionType::read_write_list() {
+                    match sensor.read_raw(sub_type).await {
+                        Ok(raw) => lines.push(echo_line(&raw, &sensor.subfunction_path(sub_type))),
+                        Err(SensorError::SubtypeNotSupported { .. }) => continue,
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        let mut lines = vec![SHEBANG.to_string()];
+
+        export(&self.currents, &mut lines).await?;
+        export(&self.energies, &mut lines).await?;
+        export(&self.fans, &mut lines).await?;
+        export(&self.humidities, &mut lines).await?;
+        export(&self.intrusions, &mut lines).await?;
+        export(&self.powers, &mut lines).await?;
+        export(&self.pwms, &mut lines).await?;
+        export(&self.temps, &mut lines).await?;
+        export(&self.voltages, &mut lines).await?;
+
+        lines.push(String::new());
+
+        Ok(lines.join("\n"))
+    }
+
     /// Set whether this hwmon beeps if an alarm condition exists.
     /// If the hwmon does not expose the value, an error is returned.
     pub async fn set_beep_enable(&self, beep_enable: bool) -> Result<()> {
@@ -427,7 +1378,7 @@ impl Eq for Hwmon {}
 
 impl PartialOrd for Hwmon {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.path.partial_cmp(other.path())
+        Some(self.cmp(other))
     }
 }
 
@@ -452,12 +1403,27 @@ impl AsyncParseable for Hwmon {
     }
 }
 
+/// Trait for types that can be read from a [`Hwmon`] with a known layout.
+///
+/// Implement this for your own structs to map the sensors of a specific chip or board to named
+/// fields and read them with [`Hwmon::read_named`].
+#[async_trait::async_trait]
+pub trait AsyncFromHwmon: Sized {
+    /// The error returned if the type can't be read from the hwmon.
+    type Error;
+
+    /// Reads this type from the given hwmon.
+    async fn from_hwmon(hwmon: &Hwmon) -> std::result::Result<Self, Self::Error>;
+}
+
 /// This crate's central struct.
 /// It stores all parsed [`Hwmon`]s which you can query either by name, device path or index.
 #[derive(Debug, Clone)]
 pub struct Hwmons {
     path: PathBuf,
     hwmons: BTreeMap<u16, Hwmon>,
+    #[cfg(feature = "test_util")]
+    snapshot_dir: Option<std::sync::Arc<temp_dir::TempDir>>,
 }
 
 impl Hwmons {
@@ -466,9 +1432,55 @@ impl Hwmons {
         Self::parse_path("/sys/class/hwmon/").await
     }
 
+    /// Parses /sys/class/hwmon like `parse`, but with the given options.
+    pub async fn parse_with_options(options: ParseOptions) -> ParsingResult<Self> {
+        Self::parse_path_with_options("/sys/class/hwmon/", options).await
+    }
+
+    /// Extracts a tar archive of a captured /sys/class/hwmon tree into a temporary directory and
+    /// parses it. The temporary directory is removed once the returned object and all its clones
+    /// are dropped. This is meant for reproducing the sensor layout of other systems.
+    #[cfg(feature = "test_util")]
+    pub async fn from_snapshot_archive(tar: &Path) -> ParsingResult<Self> {
+        let dir = super::snapshot::extract_snapshot_archive(tar)?;
+
+        let mut hwmons = Self::parse_path(dir.path()).await?;
+        hwmons.snapshot_dir = Some(std::sync::Arc::new(dir));
+
+        Ok(hwmons)
+    }
+
+    /// Returns whether /sys/class/hwmon contains a hwmon with the given name.
+    /// Only the hwmons' name files are read, so this is a lot cheaper than parsing all hwmons.
+    pub async fn has_chip(name: &str) -> ParsingResult<bool> {
+        Self::has_chip_in_path("/sys/class/hwmon/", name).await
+    }
+
+    pub(crate) async fn has_chip_in_path(
+        path: impl AsRef<Path>,
+        name: &str,
+    ) -> ParsingResult<bool> {
+        let path = path.as_ref();
+
+        for entry in path.read_dir().map_err(|e| ParsingError::hwmons(e, path))? {
+            let entry = entry.map_err(|e| ParsingError::hwmons(e, path))?;
+            let entry_path = entry.path();
+
+            if !entry_path.is_dir() || !entry.file_name().to_string_lossy().starts_with("hwmon") {
+                continue;
+            }
+
+            if get_name(&entry_path).await? == name {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     /// Returns an iterator over all hwmons with the given name and their indices.
     /// Returns an empty iterator, if there is no `Hwmon` with the given name.
-    pub fn hwmons_by_name<N: AsRef<str>>(&self, name: N) -> NamedIter<N> {
+    pub fn hwmons_by_name<N: AsRef<str>>(&self, name: N) -> NamedIter<'_, N> {
         NamedIter::new(self.iter(), name)
     }
 
@@ -486,11 +1498,228 @@ impl Hwmons {
             .find(move |&hwmon| hwmon.device_path() == device_path.as_ref())
     }
 
+    /// Returns the first hwmon that contains a temp sensor with the given label.
+    /// Returns `None`, if no hwmon contains such a sensor.
+    pub async fn first_with_temp_label(&self, label: &str) -> Option<&Hwmon> {
+        for hwmon in self.hwmons.values() {
+            if hwmon.temp_by_label(label).await.is_some() {
+                return Some(hwmon);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the sum of the inputs of all power sensors of all parsed hwmons.
+    /// Power sensors whose input can't be read or parsed are skipped and don't contribute to the sum.
+    /// Returns an error pointing at the input of the power sensor whose addition overflowed,
+    /// if the sum can't be represented as a `Power`.
+    pub async fn total_power(&self) -> Result<Power> {
+        self.sum_power(false).await
+    }
+
+    /// Returns the sum of the inputs of all power sensors of all parsed hwmons, but only counts the
+    /// first hwmon of every device. This prevents counting the same power multiple times, if a device
+    /// exposes it through multiple hwmons. Hwmons without a device are always counted.
+    /// Power sensors whose input can't be read or parsed are skipped and don't contribute to the sum.
+    /// Returns an error pointing at the input of the power sensor whose addition overflowed,
+    /// if the sum can't be represented as a `Power`.
+    pub async fn total_power_deduped(&self) -> Result<Power> {
+        self.sum_power(true).await
+    }
+
+    async fn sum_power(&self, dedup_devices: bool) -> Result<Power> {
+        let mut devices = BTreeSet::new();
+        let mut total = Power::try_from_raw_integer(0).map_err(|e| Error::unit(e, &self.path))?;
+
+        for hwmon in self.hwmons.values() {
+            if dedup_devices {
+                if let Ok(device_path) = hwmon.path().join("device").canonicalize() {
+                    if !devices.insert(device_path) {
+                        continue;
+                    }
+                }
+            }
+
+            for power in hwmon.powers.values() {
+                let Ok(input) = power.read_input().await else {
+                    continue;
+                };
+
+                total =
+                    Power::try_from_raw_integer(total.to_raw_integer() + input.to_raw_integer())
+                        .map_err(|e| {
+                            Error::unit(e, power.subfunction_path(SensorSubFunctionType::Input))
+                        })?;
+            }
+        }
+
+        Ok(total)
+    }
+
+    /// Sets whether the alarm subfunctions of all sensors of the hwmons with the given name are
+    /// active low, meaning `0` signals an alarm.
+    /// This is only needed for the few drivers that invert the polarity of their alarms.
+    pub fn set_alarm_polarity(&mut self, name: impl AsRef<str>, active_low: bool) {
+        for hwmon in self.hwmons.values_mut() {
+            if hwmon.name() == name.as_ref() {
+                hwmon.set_alarm_polarity(active_low);
+            }
+        }
+    }
+
+    /// Overrides the names of all parsed hwmons' sensors with the labels found in the lm-sensors style
+    /// configuration file at `conf`, so that sensor names match the ones displayed by `sensors(1)`.
+    /// The supported grammar is a minimal subset of `sensors.conf(5)`:
+    /// `chip "pattern" ...` starts a section for all hwmons whose name matches the part of one of the
+    /// patterns before the first `-` (or `*` for all hwmons), `label feature "text"` overrides the name
+    /// of a sensor like `temp1` or `in0` in that section and `#` starts a comment.
+    /// All other statements are ignored.
+    pub async fn load_labels_from(&mut self, conf: impl AsRef<Path>) -> Result<()> {
+        let conf = conf.as_ref();
+        let contents = read_to_string(conf).await.map_err(|e| Error::io(e, conf))?;
+
+        for hwmon in self.hwmons.values_mut() {
+            hwmon.apply_labels(&contents);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the sensor that owns the given sysfs file like `.../hwmon0/temp1_input`.
+    /// This is useful for paths from external sources like udev or inotify events.
+    /// Returns `None`, if the file doesn't belong to a sensor of any of the parsed hwmons.
+    pub fn sensor_by_path(&self, sysfs_file: &Path) -> Option<AsyncAnySensor> {
+        let (kind, index) =
+            SensorKind::parse_file_name(&sysfs_file.file_name()?.to_string_lossy())?;
+        let hwmon_path = sysfs_file.parent()?;
+        let canonical_hwmon_path = hwmon_path.canonicalize().ok();
+
+        self.hwmons
+            .values()
+            .find(|hwmon| {
+                hwmon.path() == hwmon_path
+                    || canonical_hwmon_path.is_some()
+                        && hwmon.path().canonicalize().ok() == canonical_hwmon_path
+            })?
+            .any_sensor(kind, index)
+    }
+
+    /// Returns clones of all sensors of all hwmons, ordered by hwmon index and then by
+    /// `Hwmon::display_order`, so a global dashboard can iterate over them once.
+    /// Each sensor is returned together with the index and name of the hwmon it belongs to.
+    pub fn all_sensors(&self) -> Vec<(u16, String, AsyncAnySensor)> {
+        self.hwmons
+            .values()
+            .flat_map(|hwmon| {
+                hwmon
+                    .display_order()
+                    .into_iter()
+                    .map(|sensor| (hwmon.index(), hwmon.name().to_string(), sensor))
+            })
+            .collect()
+    }
+
+    /// Groups all hwmons by the canonical path of their device, so that devices exposing multiple
+    /// hwmons like some GPUs can be displayed as one.
+    /// Hwmons without a device are omitted.
+    pub fn group_by_device(&self) -> BTreeMap<PathBuf, Vec<&Hwmon>> {
+        let mut groups: BTreeMap<PathBuf, Vec<&Hwmon>> = BTreeMap::new();
+
+        for hwmon in self.hwmons.values() {
+            if let Ok(device_path) = hwmon.path().join("device").canonicalize() {
+                groups.entry(device_path).or_default().push(hwmon);
+            }
+        }
+
+        groups
+    }
+
+    /// Returns owned clones of all hwmons ordered by their indices, e.g. to move each of them into its
+    /// own worker thread.
+    /// Cloning is cheap, since a hwmon only stores its paths and sensor metadata and no file handles.
+    pub fn cloned_hwmons(&self) -> Vec<Hwmon> {
+        self.hwmons.values().cloned().collect()
+    }
+
+    /// Returns a multi-line description of all hwmons listing their index, name, driver and the
+    /// number of sensors of each kind, e.g. for logging at startup.
+    /// This doesn't read any sensor values.
+    pub fn describe(&self) -> String {
+        let mut description = format!("Found {} hwmon(s)\n", self.hwmons.len());
+
+        for hwmon in self.hwmons.values() {
+            let counts = hwmon.sensor_counts();
+            let sensors = SensorKind::all()
+                .iter()
+                .filter(|&&kind| counts.get(kind) > 0)
+                .map(|&kind| format!("{}: {}", kind, counts.get(kind)))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            description.push_str(&format!(
+                "hwmon{}: {} (driver: {}) [{}]\n",
+                hwmon.index(),
+                hwmon.name(),
+                hwmon.driver().as_deref().unwrap_or("none"),
+                sensors
+            ));
+        }
+
+        description
+    }
+
+    /// Renders all hwmons in the text layout of `sensors(1)`: a header with the chip identifier
+    /// and adapter followed by one `label: value` line per sensor.
+    /// Sensors whose input can't be read or parsed are shown as `N/A`.
+    /// Pwms and intrusion sensors are omitted like in `sensors(1)`.
+    pub async fn to_sensors_output(&self) -> String {
+        let mut output = String::new();
+
+        for hwmon in self.hwmons.values() {
+            let mut sensors = hwmon.all_dyn_sensors();
+            sensors.retain(|sensor| sort_key(sensor.base()).is_some());
+            sensors.sort_by_key(|sensor| (sort_key(sensor.base()), sensor.index()));
+
+            let mut readings = Vec::with_capacity(sensors.len());
+
+            for sensor in &sensors {
+                let reading = sensor
+                    .read_input_raw()
+                    .await
+                    .ok()
+                    .and_then(|raw| format_reading(sensor.base(), &raw))
+                    .unwrap_or_else(|| String::from("N/A"));
+
+                readings.push((sensor.name().await, reading));
+            }
+
+            output.push_str(&format_chip(
+                &hwmon.chip_id(),
+                adapter_name(hwmon.subsystem().as_deref()),
+                &readings,
+            ));
+        }
+
+        output
+    }
+
     /// Returns an iterator over all hwmons, their names and their indices.
-    pub fn iter(&self) -> Iter {
+    pub fn iter(&self) -> Iter<'_> {
         Iter::new(self.hwmons.iter())
     }
 
+    /// Returns an endless stream that rescans the parsed directory every `poll` and yields an event
+    /// whenever a hwmon appears or disappears, e.g. because a USB temp probe was connected.
+    /// The hwmons contained in this object are considered to be known at the start.
+    /// The stream must be polled from within a tokio runtime.
+    pub fn watch_devices(&self, poll: Duration) -> DeviceWatchStream {
+        DeviceWatchStream::new(
+            DeviceWatcher::new(&self.path, self.hwmons.keys().copied()),
+            poll,
+        )
+    }
+
     /// Parses the provided path and returns the found hwmons as a Hwmons object.
     #[cfg(feature = "unrestricted_parsing")]
     pub async fn parse_unrestricted(path: impl AsRef<Path>) -> ParsingResult<Self> {
@@ -504,11 +1733,20 @@ impl Hwmons {
     }
 
     pub(crate) async fn parse_path(path: impl AsRef<Path>) -> ParsingResult<Self> {
+        Self::parse_path_with_options(path, ParseOptions::default()).await
+    }
+
+    pub(crate) async fn parse_path_with_options(
+        path: impl AsRef<Path>,
+        options: ParseOptions,
+    ) -> ParsingResult<Self> {
         let path = path.as_ref();
 
         let mut hwmons = Hwmons {
             path: path.to_path_buf(),
             hwmons: BTreeMap::new(),
+            #[cfg(feature = "test_util")]
+            snapshot_dir: None,
         };
 
         let mut index;
@@ -531,9 +1769,13 @@ impl Hwmons {
                 continue;
             }
 
-            hwmons
-                .hwmons
-                .insert(index, Hwmon::try_from_path(entry_path, index).await?);
+            let mut hwmon = Hwmon::try_from_path(entry_path, index).await?;
+
+            if options.precompute_caps {
+                hwmon.precompute_capabilities();
+            }
+
+            hwmons.hwmons.insert(index, hwmon);
         }
 
         Ok(hwmons)
@@ -62,3 +62,92 @@ async fn test_hwmon_pwms() {
 
     assert_eq!(true, pwms.get(&3u16).is_none());
 }
+
+#[tokio::test]
+async fn test_sensor_poller() {
+    use crate::sensors::async_sensors::poller::SensorPoller;
+    use crate::units::{Raw, Temperature};
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).await.unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let mut poller = SensorPoller::new(Duration::from_millis(10));
+    let mut receiver = poller.register(hwmon.temp(1).unwrap().clone());
+    let handle = poller.spawn();
+
+    receiver.changed().await.unwrap();
+    assert_eq!(
+        Some(Temperature::from_raw("40000").unwrap()),
+        *receiver.borrow_and_update()
+    );
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_input"), "50000").unwrap();
+
+    receiver
+        .wait_for(|value| *value == Some(Temperature::from_raw("50000").unwrap()))
+        .await
+        .unwrap();
+
+    drop(receiver);
+    handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_watch_devices() {
+    use crate::hwmon::HwmonEvent;
+    use futures_core::Stream;
+    use std::pin::Pin;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).await.unwrap();
+    let mut events = hwmons.watch_devices(Duration::from_millis(10));
+
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "usb_probe");
+
+    let event = std::future::poll_fn(|cx| Pin::new(&mut events).poll_next(cx)).await;
+    assert_eq!(Some(HwmonEvent::Added(1)), event);
+}
+
+#[cfg(feature = "writeable")]
+#[tokio::test]
+async fn test_write_all_pwms() {
+    use crate::units::Pwm;
+    use std::collections::BTreeMap;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "nct6775")
+        .add_pwm(1, true, true)
+        .add_pwm(2, true, true)
+        .add_pwm(3, true, true);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).await.unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let values = BTreeMap::from([
+        (1, Pwm::from_u8(64)),
+        (2, Pwm::from_u8(128)),
+        (3, Pwm::from_u8(255)),
+        (4, Pwm::from_u8(0)),
+    ]);
+    let results = hwmon.write_all_pwms(&values).await;
+
+    assert_eq!(4, results.len());
+    assert!(results[..3].iter().all(|(_, result)| result.is_ok()));
+    assert_eq!(4, results[3].0);
+    assert!(results[3].1.is_err());
+
+    for (index, value) in [(1, "64"), (2, "128"), (3, "255")] {
+        assert_eq!(
+            value,
+            std::fs::read_to_string(test_dir.path().join(format!("hwmon0/pwm{}", index))).unwrap()
+        );
+    }
+}
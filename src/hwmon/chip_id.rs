@@ -0,0 +1,56 @@
+//! Helper for composing libsensors style chip identifiers like `coretemp-isa-0000`.
+
+/// Composes the libsensors style identifier of a chip from its name and the subsystem and
+/// sysfs name of its device. Falls back to `name-virtual-index`, if the bus is not known.
+pub(crate) fn chip_id(
+    name: &str,
+    subsystem: Option<&str>,
+    bus_address: Option<&str>,
+    index: u16,
+) -> String {
+    let bus_id = match (subsystem, bus_address) {
+        (Some("platform" | "of_platform" | "isa"), Some(address)) => Some(isa_id(address)),
+        (Some("pci"), Some(address)) => pci_id(address),
+        (Some("i2c"), Some(address)) => i2c_id(address),
+        _ => None,
+    };
+
+    match bus_id {
+        Some(bus_id) => format!("{}-{}", name, bus_id),
+        None => format!("{}-virtual-{}", name, index),
+    }
+}
+
+/// Platform devices are named like `coretemp.0`. Devices without a numeric suffix use address 0.
+fn isa_id(address: &str) -> String {
+    let address = address
+        .rsplit_once('.')
+        .and_then(|(_, address)| address.parse::<u32>().ok())
+        .unwrap_or(0);
+
+    format!("isa-{:04x}", address)
+}
+
+/// Pci devices are named like `0000:03:00.0` (domain:bus:slot.function).
+fn pci_id(address: &str) -> Option<String> {
+    let (domain, rest) = address.split_once(':')?;
+    let (bus, rest) = rest.split_once(':')?;
+    let (slot, function) = rest.split_once('.')?;
+
+    let address = (u32::from_str_radix(domain, 16).ok()? << 16)
+        + (u32::from_str_radix(bus, 16).ok()? << 8)
+        + (u32::from_str_radix(slot, 16).ok()? << 3)
+        + u32::from_str_radix(function, 16).ok()?;
+
+    Some(format!("pci-{:04x}", address))
+}
+
+/// I2c devices are named like `0-0048` (bus-address).
+fn i2c_id(address: &str) -> Option<String> {
+    let (bus, address) = address.split_once('-')?;
+
+    let bus = bus.parse::<u32>().ok()?;
+    let address = u32::from_str_radix(address, 16).ok()?;
+
+    Some(format!("i2c-{}-{:02x}", bus, address))
+}
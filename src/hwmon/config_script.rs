@@ -0,0 +1,35 @@
+//! Helpers for exporting a hwmon's configuration as a shell script.
+
+use std::path::Path;
+
+/// The first line of every exported configuration script.
+pub(crate) const SHEBANG: &str = "#!/bin/sh";
+
+/// Quotes the given string for use as a single word in a posix shell.
+pub(crate) fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+/// Returns a shell command that writes `value` to the file at `path`.
+pub(crate) fn echo_line(value: &str, path: &Path) -> String {
+    format!(
+        "echo {} > {}",
+        shell_quote(value.trim()),
+        shell_quote(&path.to_string_lossy())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!("'128'", shell_quote("128"));
+        assert_eq!(r"'it'\''s'", shell_quote("it's"));
+        assert_eq!(
+            "echo '1' > '/sys/class/hwmon/hwmon0/pwm1_enable'",
+            echo_line("1\n", Path::new("/sys/class/hwmon/hwmon0/pwm1_enable"))
+        );
+    }
+}
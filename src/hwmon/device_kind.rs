@@ -0,0 +1,61 @@
+//! Module containing the classification of the devices hwmons belong to.
+
+use std::fmt::{Display, Formatter, Result};
+use std::path::Path;
+
+/// The kind of device a hwmon belongs to, derived from the subsystem of its `device` link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeviceKind {
+    /// A device on the pci bus like a gpu.
+    Pci,
+    /// A platform device like the cpu's temperature sensor.
+    Platform,
+    /// A device on an i2c bus like a pmbus power supply.
+    I2c,
+    /// A device on the usb bus.
+    Usb,
+    /// A hwmon without a device or with a device below `/sys/devices/virtual`.
+    Virtual,
+    /// A device on any other bus.
+    Other,
+}
+
+impl DeviceKind {
+    /// Classifies a hwmon's device by its subsystem name and canonical device path.
+    /// Both are `None`, if the hwmon has no device.
+    pub(crate) fn classify(subsystem: Option<&str>, device_path: Option<&Path>) -> Self {
+        let Some(device_path) = device_path else {
+            return DeviceKind::Virtual;
+        };
+
+        if device_path
+            .components()
+            .any(|component| component.as_os_str() == "virtual")
+        {
+            return DeviceKind::Virtual;
+        }
+
+        match subsystem {
+            Some("pci") => DeviceKind::Pci,
+            Some("platform") => DeviceKind::Platform,
+            Some("i2c") => DeviceKind::I2c,
+            Some("usb") => DeviceKind::Usb,
+            _ => DeviceKind::Other,
+        }
+    }
+}
+
+impl Display for DeviceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let name = match self {
+            DeviceKind::Pci => "pci",
+            DeviceKind::Platform => "platform",
+            DeviceKind::I2c => "i2c",
+            DeviceKind::Usb => "usb",
+            DeviceKind::Virtual => "virtual",
+            DeviceKind::Other => "other",
+        };
+
+        write!(f, "{}", name)
+    }
+}
@@ -0,0 +1,90 @@
+//! Module containing the functionality to watch for hwmons being added or removed.
+
+use std::collections::{BTreeSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+/// Event that is emitted when a hwmon appears or disappears.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HwmonEvent {
+    /// A hwmon with the given index was added.
+    Added(u16),
+    /// The hwmon with the given index was removed.
+    Removed(u16),
+}
+
+/// Keeps track of the hwmon indices found in a directory and the events that were not yet consumed.
+#[derive(Debug, Clone)]
+pub(crate) struct DeviceWatcher {
+    path: PathBuf,
+    known: BTreeSet<u16>,
+    pending: VecDeque<HwmonEvent>,
+}
+
+impl DeviceWatcher {
+    pub(crate) fn new(path: impl Into<PathBuf>, known: impl IntoIterator<Item = u16>) -> Self {
+        Self {
+            path: path.into(),
+            known: known.into_iter().collect(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn next_pending(&mut self) -> Option<HwmonEvent> {
+        self.pending.pop_front()
+    }
+
+    /// Returns the path of the watched directory.
+    #[cfg(feature = "async")]
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Scans the watched directory and queues an event for every hwmon that was added or removed.
+    #[cfg(feature = "sync")]
+    pub(crate) fn scan(&mut self) {
+        self.update(scan_indices(&self.path));
+    }
+
+    /// Queues an event for every hwmon that was added or removed according to the indices found
+    /// by a scan of the watched directory.
+    /// A failed scan is ignored, so that a temporarily unreadable directory doesn't report all
+    /// hwmons as removed.
+    pub(crate) fn update(&mut self, current: Option<BTreeSet<u16>>) {
+        let Some(current) = current else {
+            return;
+        };
+
+        self.pending.extend(
+            current
+                .difference(&self.known)
+                .map(|&index| HwmonEvent::Added(index)),
+        );
+        self.pending.extend(
+            self.known
+                .difference(&current)
+                .map(|&index| HwmonEvent::Removed(index)),
+        );
+
+        self.known = current;
+    }
+}
+
+/// Returns the indices of all hwmons in the given directory or `None`, if it can't be read.
+/// This blocks on filesystem access.
+pub(crate) fn scan_indices(path: &Path) -> Option<BTreeSet<u16>> {
+    let indices = path
+        .read_dir()
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .strip_prefix("hwmon")
+                .and_then(|index| index.parse().ok())
+        })
+        .collect();
+
+    Some(indices)
+}
@@ -39,6 +39,12 @@ pub enum Error {
         /// The path where the error occurred.
         path: PathBuf,
     },
+
+    /// The given attribute path is absolute or leaves the device directory.
+    InvalidAttributePath {
+        /// The offending path.
+        path: PathBuf,
+    },
 }
 
 impl Error {
@@ -62,6 +68,10 @@ impl Error {
         Error::Unit { source, path }
     }
 
+    pub(crate) fn invalid_attribute_path(path: impl Into<PathBuf>) -> Self {
+        Self::InvalidAttributePath { path: path.into() }
+    }
+
     #[cfg(feature = "writeable")]
     pub(crate) fn insufficient_rights(path: impl Into<PathBuf>) -> Self {
         Self::InsufficientRights { path: path.into() }
@@ -76,6 +86,7 @@ impl StdError for Error {
             Error::Io { source, .. } => Some(source),
             Error::Unit { source, .. } => Some(source),
             Error::InsufficientRights { .. } => None,
+            Error::InvalidAttributePath { .. } => None,
         }
     }
 }
@@ -102,6 +113,13 @@ impl Display for Error {
                     path.display()
                 )
             }
+            Error::InvalidAttributePath { path } => {
+                write!(
+                    f,
+                    "Attribute path {} is not inside the device directory",
+                    path.display()
+                )
+            }
         }
     }
 }
@@ -1,6 +1,25 @@
 //! Module containing the Hwmon struct and related functionality.
 
+mod chip_id;
+#[cfg(feature = "writeable")]
+mod config_script;
+mod device_kind;
+mod device_watch;
 mod error;
+mod parse_options;
+#[cfg(feature = "writeable")]
+mod pwm_fan_correlation;
+mod read_options;
+mod sensor_counts;
+mod sensors_conf;
+mod sensors_output;
+mod uevent;
+
+#[cfg(feature = "test_util")]
+mod snapshot;
+
+#[cfg(feature = "writeable")]
+mod update_interval;
 
 #[cfg(feature = "sync")]
 pub mod sync_hwmon;
@@ -8,4 +27,9 @@ pub mod sync_hwmon;
 #[cfg(feature = "async")]
 pub mod async_hwmon;
 
+pub use device_kind::DeviceKind;
+pub use device_watch::HwmonEvent;
 pub use error::Error;
+pub use parse_options::ParseOptions;
+pub use read_options::ReadOptions;
+pub use sensor_counts::SensorCounts;
@@ -0,0 +1,11 @@
+//! Module containing options for parsing hwmons.
+
+/// Options that control how much work is done while parsing hwmons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ParseOptions {
+    /// Whether the supported read subfunctions of every sensor are probed once during parsing
+    /// and cached, so that `Hwmon::capabilities` doesn't touch the filesystem afterwards.
+    /// The cache is never refreshed. This is fine for sysfs, where a sensor's files don't appear
+    /// or disappear at runtime, but makes parsing slower.
+    pub precompute_caps: bool,
+}
@@ -0,0 +1,66 @@
+//! Helpers for experimentally correlating pwms with the fans they drive.
+
+use crate::units::{AngularVelocity, Pwm, RawInteger};
+
+use std::collections::BTreeMap;
+
+/// The minimum change in rpm a fan has to show to be considered driven by a perturbed pwm.
+pub(crate) const MIN_RPM_DELTA: u64 = 100;
+
+/// Returns the value a pwm is temporarily set to while finding out which fan it drives.
+/// Slow pwms are turned up to full speed, fast ones are halved, so fans are never stopped.
+pub(crate) fn perturbed_pwm(original: Pwm) -> Pwm {
+    if original.as_u8() < 128 {
+        Pwm::from_u8(u8::MAX)
+    } else {
+        Pwm::from_u8(original.as_u8() / 2)
+    }
+}
+
+/// Returns the index of the fan whose rpm changed the most between `baseline` and `perturbed`,
+/// if that change is at least `MIN_RPM_DELTA`.
+pub(crate) fn responding_fan(
+    baseline: &BTreeMap<u16, AngularVelocity>,
+    perturbed: &BTreeMap<u16, AngularVelocity>,
+) -> Option<u16> {
+    baseline
+        .iter()
+        .filter_map(|(index, before)| {
+            perturbed.get(index).map(|after| {
+                let delta = before.to_raw_integer().abs_diff(after.to_raw_integer());
+                (*index, delta)
+            })
+        })
+        .filter(|(_, delta)| *delta >= MIN_RPM_DELTA)
+        .max_by_key(|(_, delta)| *delta)
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rpms<const N: usize>(rpms: [(u16, i64); N]) -> BTreeMap<u16, AngularVelocity> {
+        rpms.into_iter()
+            .map(|(index, rpm)| (index, AngularVelocity::try_from_raw_integer(rpm).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_perturbed_pwm() {
+        assert_eq!(255, perturbed_pwm(Pwm::from_u8(0)).as_u8());
+        assert_eq!(255, perturbed_pwm(Pwm::from_u8(127)).as_u8());
+        assert_eq!(100, perturbed_pwm(Pwm::from_u8(200)).as_u8());
+    }
+
+    #[test]
+    fn test_responding_fan() {
+        let baseline = rpms([(1, 1000), (2, 1200), (3, 800)]);
+
+        let perturbed = rpms([(1, 1050), (2, 2500), (3, 780)]);
+        assert_eq!(Some(2), responding_fan(&baseline, &perturbed));
+
+        let perturbed = rpms([(1, 1050), (2, 1150), (3, 780)]);
+        assert_eq!(None, responding_fan(&baseline, &perturbed));
+    }
+}
@@ -0,0 +1,50 @@
+//! Module containing options for reading many sensors at once.
+
+use crate::sensors::Error as SensorError;
+
+/// Options that control how `Hwmon::read_all_temps` handles failing sensors.
+/// Currently `read_all_temps` is the only bulk reader that takes these options. `Hwmon::dump_raw`
+/// always skips every file it can't read and `Hwmons::all_sensors` doesn't read at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ReadOptions {
+    /// Whether sensors that can't be read due to insufficient rights are skipped instead of
+    /// failing the whole read. This lets unprivileged users read whatever is accessible.
+    pub skip_permission_errors: bool,
+}
+
+impl ReadOptions {
+    /// Returns `Ok(None)` for errors these options allow skipping and passes everything else on.
+    pub(crate) fn skippable<T>(
+        &self,
+        result: Result<T, SensorError>,
+    ) -> Result<Option<T>, SensorError> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(SensorError::InsufficientRights { .. }) if self.skip_permission_errors => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skippable() {
+        let skip = ReadOptions {
+            skip_permission_errors: true,
+        };
+        let strict = ReadOptions::default();
+
+        assert_eq!(Some(1), skip.skippable(Ok(1)).unwrap());
+        assert_eq!(
+            None,
+            skip.skippable::<u8>(Err(SensorError::insufficient_rights("temp1_input")))
+                .unwrap()
+        );
+        assert!(strict
+            .skippable::<u8>(Err(SensorError::insufficient_rights("temp1_input")))
+            .is_err());
+    }
+}
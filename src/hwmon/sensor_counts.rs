@@ -0,0 +1,40 @@
+//! Module containing the number of sensors of each kind found in a hwmon.
+
+use crate::sensors::SensorKind;
+
+/// The number of sensors of each kind found in a hwmon.
+#[allow(missing_docs)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SensorCounts {
+    pub currents: usize,
+    pub energies: usize,
+    pub fans: usize,
+    pub humidities: usize,
+    pub intrusions: usize,
+    pub powers: usize,
+    pub pwms: usize,
+    pub temps: usize,
+    pub voltages: usize,
+}
+
+impl SensorCounts {
+    /// Returns the number of sensors of the given kind.
+    pub fn get(&self, kind: SensorKind) -> usize {
+        match kind {
+            SensorKind::Current => self.currents,
+            SensorKind::Energy => self.energies,
+            SensorKind::Fan => self.fans,
+            SensorKind::Humidity => self.humidities,
+            SensorKind::Intrusion => self.intrusions,
+            SensorKind::Power => self.powers,
+            SensorKind::Pwm => self.pwms,
+            SensorKind::Temp => self.temps,
+            SensorKind::Voltage => self.voltages,
+        }
+    }
+
+    /// Returns the number of sensors of all kinds.
+    pub fn total(&self) -> usize {
+        SensorKind::all().iter().map(|&kind| self.get(kind)).sum()
+    }
+}
@@ -0,0 +1,92 @@
+//! Minimal parser for the label statements of lm-sensors style configuration files.
+//!
+//! Only `chip` and `label` statements and `#` comments are understood.
+//! Only the part of a chip pattern before the first `-` is compared against the hwmon's name.
+//! All other statements (`compute`, `set`, `ignore`, `bus`, ...) are ignored.
+
+/// A label override parsed from a configuration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LabelOverride {
+    pub(crate) base: String,
+    pub(crate) index: u16,
+    pub(crate) label: String,
+}
+
+/// Returns all label overrides in `conf` that apply to the hwmon with the given name.
+pub(crate) fn parse_labels(conf: &str, hwmon_name: &str) -> Vec<LabelOverride> {
+    let mut chip_matches = false;
+    let mut labels = Vec::new();
+
+    for line in conf.lines() {
+        let tokens = tokenize(line);
+
+        match tokens.split_first() {
+            Some((keyword, patterns)) if keyword == "chip" => {
+                chip_matches = patterns
+                    .iter()
+                    .any(|pattern| pattern_matches(pattern, hwmon_name));
+            }
+            Some((keyword, [feature, label, ..])) if keyword == "label" && chip_matches => {
+                if let Some((base, index)) = split_feature(feature) {
+                    labels.push(LabelOverride {
+                        base: base.to_string(),
+                        index,
+                        label: label.clone(),
+                    });
+                }
+            }
+            _ => continue,
+        }
+    }
+
+    labels
+}
+
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for ch in line.chars() {
+        match ch {
+            '"' => {
+                if in_quotes {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                in_quotes = !in_quotes;
+            }
+            '#' if !in_quotes => break,
+            ch if ch.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            ch => current.push(ch),
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+fn pattern_matches(pattern: &str, hwmon_name: &str) -> bool {
+    match pattern.split('-').next() {
+        Some("*") => true,
+        Some(prefix) => prefix == hwmon_name,
+        None => false,
+    }
+}
+
+fn split_feature(feature: &str) -> Option<(&str, u16)> {
+    let digits_start = feature.find(|ch: char| ch.is_ascii_digit())?;
+    let (base, index) = feature.split_at(digits_start);
+
+    if base.is_empty() {
+        return None;
+    }
+
+    index.parse().ok().map(|index| (base, index))
+}
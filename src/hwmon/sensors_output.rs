@@ -0,0 +1,100 @@
+//! Helpers for rendering hwmons in the text layout of `sensors(1)`.
+
+use std::fmt::Write;
+
+/// Returns the adapter description `sensors(1)` prints for devices of the given subsystem.
+pub(crate) fn adapter_name(subsystem: Option<&str>) -> &'static str {
+    match subsystem {
+        Some("platform" | "of_platform" | "isa") => "ISA adapter",
+        Some("pci") => "PCI adapter",
+        Some("i2c") => "I2C adapter",
+        Some("acpi") => "ACPI interface",
+        Some("hid") => "HID adapter",
+        _ => "Virtual device",
+    }
+}
+
+/// Returns the position of sensors with the given base in the output of `sensors(1)`.
+/// Returns `None` for sensors `sensors(1)` doesn't print.
+pub(crate) fn sort_key(base: &str) -> Option<u8> {
+    match base {
+        "in" => Some(0),
+        "fan" => Some(1),
+        "temp" => Some(2),
+        "power" => Some(3),
+        "energy" => Some(4),
+        "curr" => Some(5),
+        "humidity" => Some(6),
+        _ => None,
+    }
+}
+
+/// Returns the position of sensors with the given base when listing all sensors of a hwmon.
+/// Sensors `sensors(1)` prints keep their position and the remaining kinds are placed after them.
+pub(crate) fn display_rank(base: &str) -> u8 {
+    match base {
+        "intrusion" => 7,
+        "pwm" => 8,
+        _ => sort_key(base).unwrap_or(u8::MAX),
+    }
+}
+
+/// Formats the raw input of a sensor with the given base like `sensors(1)` does.
+/// Returns `None`, if the raw value can't be parsed.
+pub(crate) fn format_reading(base: &str, raw: &str) -> Option<String> {
+    let value = raw.parse::<i64>().ok()?;
+
+    let reading = match base {
+        "in" => format!("{:>8.2} V", value as f64 / 1000.0),
+        "fan" => format!("{:>5} RPM", value),
+        "temp" => format!("{:>+7.1}°C", value as f64 / 1000.0),
+        "power" => format!("{:>8.2} W", value as f64 / 1_000_000.0),
+        "energy" => format!("{:>8.2} J", value as f64 / 1_000_000.0),
+        "curr" => format!("{:>8.2} A", value as f64 / 1000.0),
+        "humidity" => format!("{:>7.1} %RH", value as f64 / 1000.0),
+        _ => return None,
+    };
+
+    Some(reading)
+}
+
+/// Renders a chip's header followed by one `label: value` line per reading and a trailing empty line.
+pub(crate) fn format_chip(chip_id: &str, adapter: &str, readings: &[(String, String)]) -> String {
+    let width = readings
+        .iter()
+        .map(|(label, _)| label.chars().count() + 1)
+        .max()
+        .unwrap_or(0);
+
+    let mut output = format!("{}\nAdapter: {}\n", chip_id, adapter);
+
+    for (label, reading) in readings {
+        let _ = writeln!(
+            output,
+            "{:<width$} {}",
+            format!("{}:", label),
+            reading,
+            width = width
+        );
+    }
+
+    output.push('\n');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_reading() {
+        assert_eq!(
+            Some("  +40.5°C".to_string()),
+            format_reading("temp", "40500")
+        );
+        assert_eq!(Some("    1.20 V".to_string()), format_reading("in", "1200"));
+        assert_eq!(Some(" 1200 RPM".to_string()), format_reading("fan", "1200"));
+        assert_eq!(None, format_reading("pwm", "255"));
+        assert_eq!(None, format_reading("temp", "garbage"));
+    }
+}
@@ -0,0 +1,20 @@
+//! Helper for loading captured hwmon trees from tar archives.
+
+use crate::parsing::{Error as ParsingError, Result as ParsingResult};
+
+use std::fs::File;
+use std::path::Path;
+
+use temp_dir::TempDir;
+
+/// Extracts the tar archive at the given path into a new temporary directory.
+pub(crate) fn extract_snapshot_archive(archive: &Path) -> ParsingResult<TempDir> {
+    let dir = TempDir::new().map_err(|e| ParsingError::hwmons(e, archive))?;
+    let file = File::open(archive).map_err(|e| ParsingError::hwmons(e, archive))?;
+
+    tar::Archive::new(file)
+        .unpack(dir.path())
+        .map_err(|e| ParsingError::hwmons(e, archive))?;
+
+    Ok(dir)
+}
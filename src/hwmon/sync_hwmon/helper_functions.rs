@@ -4,7 +4,7 @@ use crate::parsing::{Error as ParsingError, Parseable, Result as ParsingResult};
 
 use std::collections::BTreeMap;
 use std::io::ErrorKind as IoErrorKind;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub(crate) fn check_path(path: impl AsRef<Path>) -> ParsingResult<()> {
     let path = path.as_ref();
@@ -24,7 +24,13 @@ pub(crate) fn get_name(path: impl AsRef<Path>) -> ParsingResult<String> {
         .map_err(|e| ParsingError::hwmon_name(e, name_path))
 }
 
-pub(crate) fn init_sensors<S>(hwmon: &Hwmon, start_index: u16) -> ParsingResult<BTreeMap<u16, S>>
+/// Parses all sensors of type `S` of the given hwmon.
+/// Sensors that can't be accessed due to insufficient rights are skipped and recorded in `inaccessible`.
+pub(crate) fn init_sensors<S>(
+    hwmon: &Hwmon,
+    start_index: u16,
+    inaccessible: &mut Vec<(String, PathBuf)>,
+) -> ParsingResult<BTreeMap<u16, S>>
 where
     S: Parseable<Parent = Hwmon>,
 {
@@ -60,6 +66,11 @@ where
             Ok(sensor) => {
                 sensors.insert(index, sensor);
             }
+            Err(ParsingError::Sensor { source, path })
+                if source.kind() == IoErrorKind::PermissionDenied =>
+            {
+                inaccessible.push((format!("{}{}", S::prefix(), index), path));
+            }
             Err(e) => match &e {
                 ParsingError::Sensor { source, .. } => {
                     if source.kind() != IoErrorKind::NotFound {
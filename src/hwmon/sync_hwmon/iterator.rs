@@ -1,6 +1,8 @@
 use super::*;
 
-use std::{collections::btree_map::Iter as BTreeIter, iter::FusedIterator};
+use crate::hwmon::device_watch::{DeviceWatcher, HwmonEvent};
+
+use std::{collections::btree_map::Iter as BTreeIter, iter::FusedIterator, time::Duration};
 
 type InnerIter<'a> = BTreeIter<'a, u16, Hwmon>;
 
@@ -85,3 +87,33 @@ impl<'a, N: AsRef<str>> Iterator for NamedIter<'a, N> {
 }
 
 impl<'a, N: AsRef<str>> FusedIterator for NamedIter<'a, N> {}
+
+/// An endless iterator that yields an event whenever a hwmon is added or removed.
+/// Every call to `next` blocks until an event occurs.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct DeviceWatchIter {
+    watcher: DeviceWatcher,
+    poll: Duration,
+}
+
+impl DeviceWatchIter {
+    pub(super) fn new(watcher: DeviceWatcher, poll: Duration) -> Self {
+        Self { watcher, poll }
+    }
+}
+
+impl Iterator for DeviceWatchIter {
+    type Item = HwmonEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.watcher.next_pending() {
+                return Some(event);
+            }
+
+            std::thread::sleep(self.poll);
+            self.watcher.scan();
+        }
+    }
+}
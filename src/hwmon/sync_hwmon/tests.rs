@@ -62,3 +62,1168 @@ fn test_hwmon_pwms() {
 
     assert_eq!(true, pwms.get(&3u16).is_none());
 }
+
+#[test]
+fn test_load_labels_from() {
+    use crate::sensors::sync_sensors::Sensor;
+
+    let test_dir = TempDir::new().unwrap();
+    let conf_path = test_dir.path().join("sensors.conf");
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 60000, "temp2");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "other").add_temp(1, 40000, "temp1");
+
+    std::fs::write(
+        &conf_path,
+        "# Label overrides\nchip \"system-*\"\n    label temp1 \"CPU Package\" # cpu\n\nchip \"foo-*\"\n    label temp1 \"Ignored\"\n",
+    )
+    .unwrap();
+
+    let mut hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    hwmons.load_labels_from(&conf_path).unwrap();
+
+    let system = hwmons.hwmon_by_index(0).unwrap();
+    let other = hwmons.hwmon_by_index(1).unwrap();
+
+    assert_eq!("CPU Package", system.temp(1).unwrap().name());
+    assert_eq!("temp2", system.temp(2).unwrap().name());
+    assert_eq!("temp1", other.temp(1).unwrap().name());
+}
+
+#[test]
+fn test_total_power() {
+    use crate::units::{Power, Raw};
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "cpu").add_power(1, 15_000_000);
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "gpu").add_power(1, 120_500_000);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    assert_eq!(
+        Power::from_raw("135500000").unwrap(),
+        hwmons.total_power().unwrap()
+    );
+    assert_eq!(
+        Power::from_raw("135500000").unwrap(),
+        hwmons.total_power_deduped().unwrap()
+    );
+}
+
+#[test]
+#[cfg(not(feature = "uom_units"))]
+fn test_total_power_overflow() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "psu")
+        .add_power(1, 3_000_000_000)
+        .add_power(2, 3_000_000_000);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    match hwmons.total_power() {
+        Err(crate::hwmon::Error::Unit { path, .. }) => assert!(path.ends_with("power2_input")),
+        other => panic!("expected a unit error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_has_chip() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "coretemp");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "nvme");
+
+    assert!(Hwmons::has_chip_in_path(test_dir.path(), "nvme").unwrap());
+    assert!(!Hwmons::has_chip_in_path(test_dir.path(), "amdgpu").unwrap());
+}
+
+#[test]
+fn test_all_dyn_sensors() {
+    use crate::sensors::sync_sensors::DynSensor;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_fan(1, 1200);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let sensors: Vec<Box<dyn DynSensor>> = hwmon.all_dyn_sensors();
+
+    assert_eq!(2, sensors.len());
+    assert_eq!("fan", sensors[0].base());
+    assert_eq!("1200", sensors[0].read_input_raw().unwrap());
+    assert_eq!("temp", sensors[1].base());
+    assert_eq!("40000", sensors[1].read_input_raw().unwrap());
+}
+
+#[test]
+fn test_chip_id() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "coretemp")
+        .add_device("platform", "coretemp.0");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "amdgpu").add_device("pci", "0000:03:00.0");
+    VirtualHwmonBuilder::create(test_dir.path(), 2, "acpitz");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    assert_eq!(
+        "coretemp-isa-0000",
+        hwmons.hwmon_by_index(0).unwrap().chip_id()
+    );
+    assert_eq!(
+        "amdgpu-pci-0300",
+        hwmons.hwmon_by_index(1).unwrap().chip_id()
+    );
+    assert_eq!(
+        "acpitz-virtual-2",
+        hwmons.hwmon_by_index(2).unwrap().chip_id()
+    );
+}
+
+#[test]
+fn test_temp_by_label() {
+    use crate::sensors::sync_sensors::Sensor;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "amdgpu")
+        .add_temp(1, 40000, "edge")
+        .add_temp(2, 50000, "junction")
+        .add_temp(3, 60000, "mem");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "coretemp").add_temp(1, 40000, "Package id 0");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let gpu = hwmons.hwmon_by_index(0).unwrap();
+    let cpu = hwmons.hwmon_by_index(1).unwrap();
+
+    assert_eq!(1, gpu.temp_edge().unwrap().index());
+    assert_eq!(2, gpu.temp_junction().unwrap().index());
+    assert_eq!(3, gpu.temp_mem().unwrap().index());
+    assert_eq!(1, cpu.temp_by_label("Package id 0").unwrap().index());
+    assert!(cpu.temp_junction().is_none());
+}
+
+#[cfg(feature = "test_util")]
+#[test]
+fn test_snapshot_round_trip() {
+    use crate::sensors::sync_sensors::temp::TempSensor;
+
+    let test_dir = TempDir::new().unwrap();
+    let dump_dir = TempDir::new().unwrap();
+    let archive_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_fan(1, 1200);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    hwmon.dump_to_dir(dump_dir.path().join("hwmon0")).unwrap();
+
+    let archive_path = archive_dir.path().join("hwmon.tar");
+    let mut builder = tar::Builder::new(std::fs::File::create(&archive_path).unwrap());
+    builder.append_dir_all(".", dump_dir.path()).unwrap();
+    builder.finish().unwrap();
+    drop(builder);
+
+    let snapshot = Hwmons::from_snapshot_archive(&archive_path).unwrap();
+    let snapshot_hwmon = snapshot.hwmon_by_index(0).unwrap();
+
+    assert_eq!(hwmon.name(), snapshot_hwmon.name());
+    assert_eq!(hwmon.fans().len(), snapshot_hwmon.fans().len());
+    assert_eq!(
+        hwmon.temp(1).unwrap().read_input().unwrap(),
+        snapshot_hwmon.temp(1).unwrap().read_input().unwrap()
+    );
+}
+
+#[test]
+fn test_inaccessible_sensors() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 50000, "temp2")
+        .add_temp(3, 60000, "temp3");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    assert!(hwmons
+        .hwmon_by_index(0)
+        .unwrap()
+        .inaccessible_sensors()
+        .is_empty());
+
+    let unreadable_path = test_dir.path().join("hwmon0/temp2_input");
+    std::fs::set_permissions(&unreadable_path, std::fs::Permissions::from_mode(0o000)).unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    // Privileged users may still be able to read the file.
+    if std::fs::read(&unreadable_path).is_ok() {
+        assert_eq!(3, hwmon.temps().len());
+        assert!(hwmon.inaccessible_sensors().is_empty());
+    } else {
+        assert_eq!(vec![&1, &3], hwmon.temps().keys().collect::<Vec<_>>());
+        assert_eq!(
+            &[("temp2".to_string(), unreadable_path)],
+            hwmon.inaccessible_sensors()
+        );
+    }
+}
+
+#[test]
+fn test_first_with_temp_label() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "nvme").add_temp(1, 40000, "Composite");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "k10temp").add_temp(1, 50000, "Tctl");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    assert_eq!(
+        "k10temp",
+        hwmons.first_with_temp_label("Tctl").unwrap().name()
+    );
+    assert!(hwmons.first_with_temp_label("Tdie").is_none());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_reset_all_history() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 50000, "temp2")
+        .add_power(1, 15_000_000);
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_reset_history"), "0").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/power1_reset_history"), "0").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let results = hwmon.reset_all_history();
+
+    assert_eq!(2, results.len());
+    assert_eq!(("power", 1), (results[0].0.as_str(), results[0].1));
+    assert_eq!(("temp", 1), (results[1].0.as_str(), results[1].1));
+    assert!(results.iter().all(|(_, _, result)| result.is_ok()));
+
+    for file in ["temp1_reset_history", "power1_reset_history"] {
+        assert_eq!(
+            "1",
+            std::fs::read_to_string(test_dir.path().join("hwmon0").join(file)).unwrap()
+        );
+    }
+    assert!(!test_dir.path().join("hwmon0/temp2_reset_history").exists());
+}
+
+#[test]
+fn test_read_named() {
+    use super::{FromHwmon, Hwmon};
+    use crate::sensors::sync_sensors::temp::TempSensor;
+    use crate::sensors::Error as SensorError;
+    use crate::sensors::SensorSubFunctionType;
+    use crate::units::{Raw, Temperature};
+
+    #[derive(Debug)]
+    struct Board {
+        cpu: Temperature,
+        vrm: Temperature,
+    }
+
+    impl FromHwmon for Board {
+        type Error = SensorError;
+
+        fn from_hwmon(hwmon: &Hwmon) -> Result<Self, Self::Error> {
+            let read = |label| {
+                hwmon
+                    .temp_by_label(label)
+                    .ok_or(SensorError::SubtypeNotSupported {
+                        sub_type: SensorSubFunctionType::Label,
+                    })?
+                    .read_input()
+            };
+
+            Ok(Board {
+                cpu: read("CPU")?,
+                vrm: read("VRM")?,
+            })
+        }
+    }
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "nct6775")
+        .add_temp(1, 40000, "CPU")
+        .add_temp(2, 55000, "VRM");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "nvme").add_temp(1, 30000, "Composite");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    let board: Board = hwmons.hwmon_by_index(0).unwrap().read_named().unwrap();
+
+    assert_eq!(Temperature::from_raw("40000").unwrap(), board.cpu);
+    assert_eq!(Temperature::from_raw("55000").unwrap(), board.vrm);
+    assert!(hwmons
+        .hwmon_by_index(1)
+        .unwrap()
+        .read_named::<Board>()
+        .is_err());
+}
+
+#[test]
+fn test_parse_kinds() {
+    use super::Hwmon;
+    use crate::sensors::SensorKind;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 3, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_fan(1, 1200)
+        .add_power(1, 15_000_000)
+        .add_voltage(0, 1200);
+
+    let hwmon = Hwmon::parse_kinds(
+        test_dir.path().join("hwmon3"),
+        &[SensorKind::Temp, SensorKind::Fan],
+    )
+    .unwrap();
+
+    assert_eq!(3, hwmon.index());
+    assert_eq!(1, hwmon.temps().len());
+    assert_eq!(1, hwmon.fans().len());
+    assert!(hwmon.powers().is_empty());
+    assert!(hwmon.voltages().is_empty());
+}
+
+#[test]
+fn test_cloned_hwmons() {
+    use crate::sensors::sync_sensors::temp::TempSensor;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "foo").add_temp(1, 40000, "temp1");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "bar").add_temp(1, 50000, "temp1");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    let handles: Vec<_> = hwmons
+        .cloned_hwmons()
+        .into_iter()
+        .map(|hwmon| std::thread::spawn(move || hwmon.temp(1).unwrap().read_input().unwrap()))
+        .collect();
+
+    let temps: Vec<_> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+
+    assert_eq!(2, temps.len());
+    assert!(temps[0] < temps[1]);
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_enable_all() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 50000, "temp2")
+        .add_fan(1, 60)
+        .add_pwm(1, true, true);
+
+    std::fs::write(test_dir.path().join("hwmon0/temp2_enable"), "0").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/pwm1_enable"), "2").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let results = hwmon.enable_all();
+
+    assert!(results.iter().all(|(_, _, result)| result.is_ok()));
+    assert!(results
+        .iter()
+        .any(|(base, index, _)| base == "temp" && *index == 2));
+
+    for file in ["temp1_enable", "temp2_enable", "fan1_enable"] {
+        assert_eq!(
+            "1",
+            std::fs::read_to_string(test_dir.path().join("hwmon0").join(file))
+                .unwrap()
+                .trim()
+        );
+    }
+    assert_eq!(
+        "2",
+        std::fs::read_to_string(test_dir.path().join("hwmon0/pwm1_enable")).unwrap()
+    );
+}
+
+#[test]
+fn test_average_temp() {
+    use crate::units::Raw;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 50000, "temp2")
+        .add_temp(3, 90000, "temp3");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp3_fault"), "1").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    assert_eq!(
+        "45000",
+        hwmon.average_temp().unwrap().unwrap().to_raw().as_ref()
+    );
+
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "empty");
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    assert!(hwmons
+        .hwmon_by_index(1)
+        .unwrap()
+        .average_temp()
+        .unwrap()
+        .is_none());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_update_interval_granularity() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    assert_eq!(
+        Duration::from_millis(1),
+        hwmon.update_interval_granularity().unwrap()
+    );
+    assert_eq!(Duration::from_secs(1), hwmon.update_interval().unwrap());
+
+    hwmon
+        .set_update_interval(Duration::from_millis(1234))
+        .unwrap();
+    assert_eq!(
+        Duration::from_millis(1234),
+        hwmon.update_interval().unwrap()
+    );
+
+    hwmon
+        .set_update_interval_rounded(Duration::from_millis(1234), Duration::from_millis(250))
+        .unwrap();
+    assert_eq!(
+        Duration::from_millis(1250),
+        hwmon.update_interval().unwrap()
+    );
+}
+
+#[test]
+fn test_watch_devices() {
+    use crate::hwmon::HwmonEvent;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let mut events = hwmons.watch_devices(Duration::from_millis(10));
+
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "usb_probe");
+    assert_eq!(Some(HwmonEvent::Added(1)), events.next());
+
+    std::fs::remove_dir_all(test_dir.path().join("hwmon0")).unwrap();
+    assert_eq!(Some(HwmonEvent::Removed(0)), events.next());
+}
+
+#[test]
+fn test_to_sensors_output() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "coretemp")
+        .add_temp(1, 45000, "Package id 0")
+        .add_temp(2, 42500, "Core 0")
+        .add_temp(3, 0, "Core 1")
+        .add_device("platform", "coretemp.0");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp3_input"), "garbage").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let output = hwmons.to_sensors_output();
+    let mut lines = output.lines();
+
+    assert_eq!(Some("coretemp-isa-0000"), lines.next());
+    assert_eq!(Some("Adapter: ISA adapter"), lines.next());
+    assert_eq!(Some("Package id 0:   +45.0°C"), lines.next());
+    assert_eq!(Some("Core 0:         +42.5°C"), lines.next());
+    assert_eq!(Some("Core 1:       N/A"), lines.next());
+    assert_eq!(Some(""), lines.next());
+}
+
+#[test]
+fn test_sensor_counts() {
+    use crate::sensors::SensorKind;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 50000, "temp2")
+        .add_fan(1, 60)
+        .add_pwm(1, true, true)
+        .add_voltage(0, 1200);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let counts = hwmons.hwmon_by_index(0).unwrap().sensor_counts();
+
+    assert_eq!(2, counts.temps);
+    assert_eq!(1, counts.get(SensorKind::Fan));
+    assert_eq!(1, counts.pwms);
+    assert_eq!(1, counts.voltages);
+    assert_eq!(0, counts.get(SensorKind::Power));
+    assert_eq!(5, counts.total());
+}
+
+#[test]
+fn test_group_by_device() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "amdgpu").add_device("pci", "0000:03:00.0");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "amdgpu_mem").add_device("pci", "0000:03:00.0");
+    VirtualHwmonBuilder::create(test_dir.path(), 2, "coretemp")
+        .add_device("platform", "coretemp.0");
+    VirtualHwmonBuilder::create(test_dir.path(), 3, "virtual");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let groups = hwmons.group_by_device();
+
+    assert_eq!(2, groups.len());
+
+    let gpu = &groups[&test_dir
+        .path()
+        .join("devices/0000:03:00.0")
+        .canonicalize()
+        .unwrap()];
+
+    assert_eq!(
+        vec!["amdgpu", "amdgpu_mem"],
+        gpu.iter().map(|hwmon| hwmon.name()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_sensor_by_path() {
+    use crate::sensors::sync_sensors::DynSensor;
+    use crate::sensors::SensorKind;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "other")
+        .add_temp(1, 50000, "CPU")
+        .add_fan(1, 60);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    let sensor = hwmons
+        .sensor_by_path(&test_dir.path().join("hwmon1/temp1_input"))
+        .unwrap();
+
+    assert_eq!(SensorKind::Temp, sensor.kind());
+    assert_eq!(test_dir.path().join("hwmon1"), sensor.hwmon_path());
+    assert_eq!("CPU", sensor.name());
+    assert_eq!("50000", sensor.read_input_raw().unwrap());
+    assert!(sensor.as_temp().is_some());
+    assert!(sensor.as_fan().is_none());
+
+    assert!(hwmons
+        .sensor_by_path(&test_dir.path().join("hwmon1/temp2_input"))
+        .is_none());
+    assert!(hwmons
+        .sensor_by_path(&test_dir.path().join("hwmon1/name"))
+        .is_none());
+}
+
+#[test]
+fn test_describe() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "k10temp")
+        .add_temp(1, 40000, "Tctl")
+        .add_device("pci", "0000:00:18.3")
+        .add_driver("k10temp");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "nct6775")
+        .add_fan(1, 60)
+        .add_pwm(1, true, true);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let description = hwmons.describe();
+    let mut lines = description.lines();
+
+    assert_eq!(Some("Found 2 hwmon(s)"), lines.next());
+    assert_eq!(
+        Some("hwmon0: k10temp (driver: k10temp) [temp: 1]"),
+        lines.next()
+    );
+    assert_eq!(
+        Some("hwmon1: nct6775 (driver: none) [fan: 1, pwm: 1]"),
+        lines.next()
+    );
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_correlate_pwm_fans() {
+    let test_dir = TempDir::new().unwrap();
+
+    let builder = VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_pwm(1, true, true)
+        .add_fan(1, 1200)
+        .add_fan(2, 0);
+    let hwmon_path = builder.path();
+
+    // Simulates fan2 being driven by pwm1 by reading its rpm straight from the pwm.
+    std::fs::remove_file(hwmon_path.join("fan2_input")).unwrap();
+    std::os::unix::fs::symlink(hwmon_path.join("pwm1"), hwmon_path.join("fan2_input")).unwrap();
+
+    // A vendor specific automatic mode that has to survive the experiment.
+    std::fs::write(hwmon_path.join("pwm1_enable"), "5").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let correlation = hwmon.correlate_pwm_fans(Duration::ZERO).unwrap();
+
+    assert_eq!(Some(&2), correlation.get(&1));
+    assert_eq!(1, correlation.len());
+
+    let hwmon_path = test_dir.path().join("hwmon0");
+    assert_eq!(
+        "0",
+        std::fs::read_to_string(hwmon_path.join("pwm1"))
+            .unwrap()
+            .trim()
+    );
+    assert_eq!(
+        "5",
+        std::fs::read_to_string(hwmon_path.join("pwm1_enable"))
+            .unwrap()
+            .trim()
+    );
+}
+
+#[test]
+fn test_device_kind() {
+    use crate::hwmon::DeviceKind;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "amdgpu").add_device("pci", "0000:03:00.0");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "coretemp")
+        .add_device("platform", "coretemp.0");
+    VirtualHwmonBuilder::create(test_dir.path(), 2, "ina219").add_device("i2c", "1-0040");
+    VirtualHwmonBuilder::create(test_dir.path(), 3, "corsaircpro").add_device("usb", "1-2:1.0");
+    VirtualHwmonBuilder::create(test_dir.path(), 4, "acpitz");
+    VirtualHwmonBuilder::create(test_dir.path(), 5, "iwlwifi_1")
+        .add_device("thermal", "virtual/thermal/cooling_device0");
+    VirtualHwmonBuilder::create(test_dir.path(), 6, "lm70").add_device("spi", "spi0.0");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    for (index, kind) in [
+        (0, DeviceKind::Pci),
+        (1, DeviceKind::Platform),
+        (2, DeviceKind::I2c),
+        (3, DeviceKind::Usb),
+        (4, DeviceKind::Virtual),
+        (5, DeviceKind::Virtual),
+        (6, DeviceKind::Other),
+    ] {
+        assert_eq!(kind, hwmons.hwmon_by_index(index).unwrap().device_kind());
+    }
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_sensors_matching() {
+    use crate::sensors::sync_sensors::DynSensor;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "coretemp")
+        .add_temp(1, 40000, "Package id 0")
+        .add_temp(2, 38000, "Core 0")
+        .add_temp(3, 39000, "Core 1");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let matching = hwmon.sensors_matching(r"^Core \d+$").unwrap();
+    let names: Vec<String> = matching.iter().map(|sensor| sensor.name()).collect();
+
+    assert_eq!(vec!["Core 0", "Core 1"], names);
+    assert!(hwmon.sensors_matching("Core (").is_err());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_export_config_script() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_pwm(1, true, true);
+
+    let pwm_path = test_dir.path().join("hwmon0/pwm1");
+    std::fs::write(&pwm_path, "128\n").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let script = hwmon.export_config_script().unwrap();
+    let pwm_line = format!("echo '128' > '{}'", pwm_path.display());
+    let enable_line = format!(
+        "echo '2' > '{}'",
+        test_dir.path().join("hwmon0/pwm1_enable").display()
+    );
+
+    assert!(script.starts_with("#!/bin/sh\n"));
+    assert!(script.contains(&pwm_line));
+    assert!(script.find(&enable_line).unwrap() < script.find(&pwm_line).unwrap());
+
+    std::fs::write(&pwm_path, "0\n").unwrap();
+
+    let status = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(&script)
+        .status()
+        .unwrap();
+
+    assert!(status.success());
+    assert_eq!("128", std::fs::read_to_string(&pwm_path).unwrap().trim());
+}
+
+#[test]
+fn test_read_device_attr() {
+    use crate::hwmon::Error;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "ina219").add_device("i2c", "1-0040");
+
+    std::fs::write(test_dir.path().join("devices/1-0040/serial"), "ABC123\n").unwrap();
+    std::os::unix::fs::symlink(
+        test_dir.path().join("hwmon0/name"),
+        test_dir.path().join("devices/1-0040/escape"),
+    )
+    .unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    assert_eq!("ABC123", hwmon.read_device_attr("serial").unwrap());
+    assert!(matches!(
+        hwmon.read_device_attr("missing"),
+        Err(Error::Io { .. })
+    ));
+    assert!(matches!(
+        hwmon.read_device_attr("../../hwmon0/name"),
+        Err(Error::InvalidAttributePath { .. })
+    ));
+    assert!(matches!(
+        hwmon.read_device_attr("/etc/hostname"),
+        Err(Error::InvalidAttributePath { .. })
+    ));
+    assert!(matches!(
+        hwmon.read_device_attr("escape"),
+        Err(Error::InvalidAttributePath { .. })
+    ));
+}
+
+#[test]
+fn test_derived_power() {
+    use crate::units::Raw;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_voltage(1, 12000)
+        .add_voltage(3, 3_000_000)
+        .add_current(1, 2000);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    assert_eq!(
+        "24000000",
+        hwmon.derived_power(1, 1).unwrap().to_raw().as_ref()
+    );
+    assert!(hwmon.derived_power(2, 1).is_err());
+    assert!(hwmon.derived_power(1, 2).is_err());
+
+    // 6000 W don't fit into a native power.
+    #[cfg(not(feature = "uom_units"))]
+    assert!(hwmon.derived_power(3, 1).is_err());
+}
+
+#[test]
+fn test_parse_with_timeout() {
+    use crate::ParsingError;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system");
+
+    assert!(Hwmons::parse_path_with_timeout(test_dir.path(), Duration::from_secs(5)).is_ok());
+
+    // Reading from a fifo blocks until someone writes to it, just like a stuck driver.
+    let name_path = test_dir.path().join("hwmon0/name");
+    std::fs::remove_file(&name_path).unwrap();
+    assert!(std::process::Command::new("mkfifo")
+        .arg(&name_path)
+        .status()
+        .unwrap()
+        .success());
+
+    assert!(matches!(
+        Hwmons::parse_path_with_timeout(test_dir.path(), Duration::from_millis(100)),
+        Err(ParsingError::Timeout { .. })
+    ));
+
+    // Unblock the worker thread.
+    std::fs::write(&name_path, "system\n").unwrap();
+}
+
+#[test]
+fn test_beep_capable_sensors() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 40000, "temp2")
+        .add_fan(1, 1200)
+        .add_fan(2, 1200);
+
+    std::fs::write(test_dir.path().join("hwmon0/temp2_beep"), "0").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/fan1_beep"), "1").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    assert_eq!(
+        vec![("fan".to_string(), 1), ("temp".to_string(), 2)],
+        hwmon.beep_capable_sensors()
+    );
+}
+
+#[test]
+fn test_all_sensor_files() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_fan(1, 1200);
+
+    let hwmon_path = test_dir.path().join("hwmon0");
+    std::fs::write(hwmon_path.join("temp1_max"), "80000").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let mut files = hwmon.all_sensor_files();
+    files.sort();
+
+    let mut expected: Vec<_> = [
+        "fan1_enable",
+        "fan1_input",
+        "temp1_enable",
+        "temp1_input",
+        "temp1_label",
+        "temp1_max",
+    ]
+    .iter()
+    .map(|file| hwmon_path.join(file))
+    .collect();
+    expected.sort();
+
+    assert_eq!(expected, files);
+}
+
+#[test]
+fn test_uevent() {
+    let test_dir = TempDir::new().unwrap();
+
+    let builder =
+        VirtualHwmonBuilder::create(test_dir.path(), 0, "amdgpu").add_device("pci", "gpu");
+    std::fs::write(
+        builder.path().join("device").join("uevent"),
+        "DRIVER=amdgpu\nPCI_CLASS=30000\nPCI_SLOT_NAME=0000:03:00.0\nMODALIAS=pci:v00001002d0000744C\n",
+    )
+    .unwrap();
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "nct6775")
+        .add_device("platform", "nct6775.656");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let uevent = hwmon.uevent().unwrap();
+
+    assert_eq!(4, uevent.len());
+    assert_eq!("pci:v00001002d0000744C", uevent["MODALIAS"]);
+    assert_eq!(Some("amdgpu".to_string()), hwmon.driver());
+    assert_eq!(Some("0000:03:00.0".to_string()), hwmon.bus_address());
+
+    let hwmon = hwmons.hwmon_by_index(1).unwrap();
+
+    assert!(hwmon.uevent().is_err());
+    assert_eq!(None, hwmon.driver());
+    assert_eq!(Some("nct6775.656".to_string()), hwmon.bus_address());
+}
+
+#[test]
+fn test_display_order() {
+    use crate::sensors::sync_sensors::DynSensor;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_pwm(1, true, true)
+        .add_temp(2, 50000, "temp2")
+        .add_temp(1, 40000, "temp1")
+        .add_power(1, 10000000)
+        .add_fan(1, 60)
+        .add_voltage(0, 1200);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let order: Vec<(&str, u16)> = hwmons
+        .hwmon_by_index(0)
+        .unwrap()
+        .display_order()
+        .iter()
+        .map(|sensor| (sensor.base(), sensor.index()))
+        .collect();
+
+    assert_eq!(
+        vec![
+            ("in", 0),
+            ("fan", 1),
+            ("temp", 1),
+            ("temp", 2),
+            ("power", 1),
+            ("pwm", 1)
+        ],
+        order
+    );
+}
+
+#[test]
+fn test_label_index() {
+    use crate::sensors::sync_sensors::DynSensor;
+    use crate::sensors::SensorKind;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "Core 0")
+        .add_temp(2, 50000, "Core 0")
+        .add_voltage(0, 1200)
+        .add_current(1, 5000)
+        .add_fan(1, 60);
+
+    std::fs::write(test_dir.path().join("hwmon0/in0_label"), "Vcore\n").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/curr1_label"), "Icore\n").unwrap();
+
+    let conf_path = test_dir.path().join("sensors.conf");
+    std::fs::write(&conf_path, "chip \"system-*\"\n    label curr1 \"Icpu\"\n").unwrap();
+
+    let mut hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    hwmons.load_labels_from(&conf_path).unwrap();
+    let index = hwmons.hwmon_by_index(0).unwrap().label_index();
+
+    assert_eq!(4, index.len());
+    assert_eq!(SensorKind::Voltage, index["Vcore"].kind());
+    assert_eq!(0, index["Vcore"].index());
+    assert_eq!(SensorKind::Current, index["Icpu"].kind());
+    assert!(!index.contains_key("Icore"));
+    assert_eq!(SensorKind::Fan, index["fan1"].kind());
+    assert_eq!(2, index["Core 0"].index());
+}
+
+#[test]
+fn test_read_all_temps_skipping_permission_errors() {
+    use crate::hwmon::ReadOptions;
+    use crate::units::Raw;
+    use std::os::unix::fs::PermissionsExt;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 50000, "temp2");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let input_path = test_dir.path().join("hwmon0/temp2_input");
+    std::fs::set_permissions(&input_path, std::fs::Permissions::from_mode(0o000)).unwrap();
+
+    let options = ReadOptions {
+        skip_permission_errors: true,
+    };
+    let temps = hwmon.read_all_temps(options).unwrap();
+
+    assert_eq!("40000", temps[&1].to_raw().as_ref());
+
+    // Privileged users may still be able to read the file.
+    if std::fs::read(&input_path).is_ok() {
+        assert_eq!(2, temps.len());
+    } else {
+        assert_eq!(1, temps.len());
+        assert!(hwmon.read_all_temps(ReadOptions::default()).is_err());
+    }
+}
+
+#[test]
+fn test_device_name() {
+    let test_dir = TempDir::new().unwrap();
+
+    let builder = VirtualHwmonBuilder::create(test_dir.path(), 0, "nct6798")
+        .add_device("platform", "nct6775.656");
+    std::fs::write(
+        builder.path().join("device").join("name"),
+        "Nuvoton NCT6798D Super I/O\n",
+    )
+    .unwrap();
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "k10temp").add_device("pci", "0000:00:18.3");
+    VirtualHwmonBuilder::create(test_dir.path(), 2, "acpitz");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    assert_eq!(
+        Some("Nuvoton NCT6798D Super I/O".to_string()),
+        hwmons.hwmon_by_index(0).unwrap().device_name()
+    );
+    assert_eq!(None, hwmons.hwmon_by_index(1).unwrap().device_name());
+    assert_eq!(None, hwmons.hwmon_by_index(2).unwrap().device_name());
+}
+
+#[test]
+fn test_all_sensors() {
+    use crate::sensors::sync_sensors::DynSensor;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "k10temp")
+        .add_temp(1, 40000, "Tctl")
+        .add_temp(2, 45000, "Tccd1");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "nct6775")
+        .add_fan(1, 60)
+        .add_pwm(1, true, true)
+        .add_voltage(0, 1200);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let sensors = hwmons.all_sensors();
+
+    assert_eq!(5, sensors.len());
+
+    let (index, name, sensor) = &sensors[0];
+    assert_eq!((0, "k10temp"), (*index, name.as_str()));
+    assert_eq!(test_dir.path().join("hwmon0"), sensor.hwmon_path());
+
+    let (index, name, sensor) = &sensors[4];
+    assert_eq!((1, "nct6775"), (*index, name.as_str()));
+    assert_eq!(test_dir.path().join("hwmon1"), sensor.hwmon_path());
+}
+
+#[test]
+fn test_update_rate_hz() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system");
+
+    let interval_path = test_dir.path().join("hwmon0/update_interval");
+    std::fs::write(&interval_path, "500").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    assert_eq!(2.0, hwmon.update_rate_hz().unwrap());
+
+    std::fs::write(&interval_path, "0").unwrap();
+    assert!(hwmon.update_rate_hz().is_err());
+}
+
+#[test]
+fn test_dump_raw() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "CPU")
+        .add_fan(1, 1200);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let dump = hwmons.hwmon_by_index(0).unwrap().dump_raw();
+
+    assert_eq!("40000", dump[&test_dir.path().join("hwmon0/temp1_input")]);
+    assert_eq!("CPU", dump[&test_dir.path().join("hwmon0/temp1_label")]);
+    assert_eq!("1200", dump[&test_dir.path().join("hwmon0/fan1_input")]);
+}
+
+#[test]
+fn test_precompute_caps() {
+    use crate::hwmon::ParseOptions;
+    use crate::sensors::{SensorKind, SensorSubFunctionType};
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_fan(1, 60)
+        .add_pwm(1, true, true);
+    std::fs::write(test_dir.path().join("hwmon0/temp1_max"), "80000").unwrap();
+
+    let options = ParseOptions {
+        precompute_caps: true,
+    };
+    let cached = Hwmons::parse_path_with_options(test_dir.path(), options).unwrap();
+    let fresh = Hwmons::parse_path(test_dir.path()).unwrap();
+    let cached = cached.hwmon_by_index(0).unwrap();
+    let fresh = fresh.hwmon_by_index(0).unwrap();
+
+    for (kind, index) in [
+        (SensorKind::Temp, 1),
+        (SensorKind::Fan, 1),
+        (SensorKind::Pwm, 1),
+    ] {
+        assert_eq!(
+            fresh.capabilities(kind, index).unwrap(),
+            cached.capabilities(kind, index).unwrap()
+        );
+    }
+
+    assert!(cached
+        .capabilities(SensorKind::Temp, 1)
+        .unwrap()
+        .contains(&SensorSubFunctionType::Max));
+    assert!(cached.capabilities(SensorKind::Temp, 2).is_none());
+    assert!(fresh.capabilities(SensorKind::Temp, 2).is_none());
+}
@@ -0,0 +1,40 @@
+//! Helpers for reading the uevent file of a hwmon's device.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Returns the path of the uevent file of the device of the hwmon at `hwmon_path`.
+pub(crate) fn uevent_path(hwmon_path: &Path) -> PathBuf {
+    hwmon_path.join("device").join("uevent")
+}
+
+/// Parses the `KEY=value` lines of a uevent file. Lines without `=` are skipped.
+pub(crate) fn parse_uevent(content: &str) -> BTreeMap<String, String> {
+    content
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+/// Reads the value of `key` from the uevent file of the device of the hwmon at `hwmon_path`.
+/// Returns `None`, if the hwmon has no device, the device has no uevent file or it lacks the key.
+pub(crate) fn uevent_value(hwmon_path: &Path, key: &str) -> Option<String> {
+    let content = std::fs::read_to_string(uevent_path(hwmon_path)).ok()?;
+
+    parse_uevent(&content).remove(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_uevent() {
+        let uevent = parse_uevent("DRIVER=amdgpu\nPCI_SLOT_NAME=0000:03:00.0\nbroken\n");
+
+        assert_eq!(2, uevent.len());
+        assert_eq!("amdgpu", uevent["DRIVER"]);
+        assert_eq!("0000:03:00.0", uevent["PCI_SLOT_NAME"]);
+    }
+}
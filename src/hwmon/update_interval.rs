@@ -0,0 +1,123 @@
+//! Helpers for adapting update intervals to the granularity a chip accepts.
+
+#[cfg(feature = "sync")]
+use super::error::Result;
+
+use std::time::Duration;
+
+/// The interval that is written to a hwmon's update_interval to find out its granularity.
+pub(crate) const PROBE_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Rounds the given interval to the nearest multiple of `granularity`.
+/// The result is never shorter than `granularity` itself.
+pub(crate) fn round_to_granularity(interval: Duration, granularity: Duration) -> Duration {
+    let granularity_millis = granularity.as_millis().max(1);
+    let steps = ((interval.as_millis() + granularity_millis / 2) / granularity_millis).max(1);
+
+    Duration::from_millis(u64::try_from(steps * granularity_millis).unwrap_or(u64::MAX))
+}
+
+/// Writes `PROBE_INTERVAL` using `write`, reads back the interval the chip applied using `read`
+/// and restores the original interval afterwards.
+/// Returns an error, if the probe or the restore fails. A failed restore leaves the chip at the
+/// probed interval.
+#[cfg(feature = "sync")]
+pub(crate) fn probe_granularity(
+    read: impl Fn() -> Result<Duration>,
+    write: impl Fn(Duration) -> Result<()>,
+) -> Result<Duration> {
+    let original = read()?;
+
+    write(PROBE_INTERVAL)?;
+    let probed = read();
+    write(original)?;
+
+    Ok(probed?.max(PROBE_INTERVAL))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_to_granularity() {
+        let granularity = Duration::from_millis(250);
+
+        for (interval, rounded) in [(0, 250), (100, 250), (374, 250), (375, 500), (1000, 1000)] {
+            assert_eq!(
+                Duration::from_millis(rounded),
+                round_to_granularity(Duration::from_millis(interval), granularity)
+            );
+        }
+
+        assert_eq!(
+            Duration::from_millis(1234),
+            round_to_granularity(Duration::from_millis(1234), PROBE_INTERVAL)
+        );
+    }
+
+    /// Simulated chip that only supports multiples of 250ms and rounds other intervals.
+    /// The write with the index `failing_write` fails with `EIO`.
+    #[cfg(feature = "sync")]
+    struct SimulatedChip {
+        interval: std::cell::Cell<Duration>,
+        writes: std::cell::Cell<usize>,
+        failing_write: Option<usize>,
+    }
+
+    #[cfg(feature = "sync")]
+    impl SimulatedChip {
+        const GRANULARITY: Duration = Duration::from_millis(250);
+
+        fn new(failing_write: Option<usize>) -> Self {
+            Self {
+                interval: std::cell::Cell::new(Duration::from_secs(1)),
+                writes: std::cell::Cell::new(0),
+                failing_write,
+            }
+        }
+
+        fn read(&self) -> Result<Duration> {
+            Ok(self.interval.get())
+        }
+
+        fn write(&self, interval: Duration) -> Result<()> {
+            let write = self.writes.get();
+            self.writes.set(write + 1);
+
+            if self.failing_write == Some(write) {
+                return Err(crate::hwmon::Error::io(
+                    std::io::Error::from_raw_os_error(5),
+                    "update_interval",
+                ));
+            }
+
+            self.interval
+                .set(round_to_granularity(interval, Self::GRANULARITY));
+
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_probe_granularity_250ms() {
+        let chip = SimulatedChip::new(None);
+
+        assert_eq!(
+            SimulatedChip::GRANULARITY,
+            probe_granularity(|| chip.read(), |i| chip.write(i)).unwrap()
+        );
+        assert_eq!(Duration::from_secs(1), chip.interval.get());
+        assert_eq!(2, chip.writes.get());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_probe_granularity_failed_restore() {
+        let chip = SimulatedChip::new(Some(1));
+
+        assert!(probe_granularity(|| chip.read(), |i| chip.write(i)).is_err());
+        assert_eq!(SimulatedChip::GRANULARITY, chip.interval.get());
+    }
+}
@@ -44,8 +44,10 @@ pub mod hwmon;
 pub mod sensors;
 pub mod units;
 
+mod error;
 mod parsing;
 
+pub use error::Error;
 pub use parsing::Error as ParsingError;
 
 /// Convenience function for [`hwmon::sync_hwmon::Hwmons::parse`](crate::hwmon::sync_hwmon::Hwmons::parse())
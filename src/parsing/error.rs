@@ -4,6 +4,7 @@ use std::{
     io::Error as IoError,
     num::ParseIntError,
     path::PathBuf,
+    time::Duration,
 };
 
 pub(crate) type Result<T> = std::result::Result<T, Error>;
@@ -28,6 +29,9 @@ pub enum Error {
 
     /// Error parsing sensor
     Sensor { source: IoError, path: PathBuf },
+
+    /// Parsing took longer than the given timeout
+    Timeout { timeout: Duration },
 }
 
 impl Error {
@@ -60,6 +64,11 @@ impl Error {
 
         Error::Sensor { source, path }
     }
+
+    #[cfg(feature = "sync")]
+    pub(crate) fn timeout(timeout: Duration) -> Self {
+        Error::Timeout { timeout }
+    }
 }
 
 impl StdError for Error {
@@ -70,6 +79,7 @@ impl StdError for Error {
             Error::HwmonDir { source, .. } => Some(source),
             Error::HwmonIndex { source, .. } => Some(source),
             Error::Sensor { source, .. } => Some(source),
+            Error::Timeout { .. } => None,
         }
     }
 }
@@ -101,6 +111,9 @@ impl Display for Error {
             Error::Sensor { source, path } => {
                 write!(f, "Error parsing sensor at {}: {}", path.display(), source)
             }
+            Error::Timeout { timeout } => {
+                write!(f, "Parsing hwmons took longer than {:?}", timeout)
+            }
         }
     }
 }
@@ -3,6 +3,7 @@
 use super::*;
 use crate::hwmon::async_hwmon::Hwmon;
 use crate::parsing::{AsyncParseable, Result as ParsingResult};
+use crate::sensors::optional;
 use crate::units::Current;
 
 #[cfg(feature = "writeable")]
@@ -12,10 +13,10 @@ use std::path::{Path, PathBuf};
 #[async_trait]
 pub trait AsyncCurrentSensor: AsyncSensor<Value = Current> + std::fmt::Debug {
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw().await? != 0)
     }
 
     /// Reads the input subfunction of this sensor.
@@ -77,36 +78,44 @@ pub trait AsyncCurrentSensor: AsyncSensor<Value = Current> + std::fmt::Debug {
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the min subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_min_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MinAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MinAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the max subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_max_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MaxAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MaxAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the crit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_crit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::CritAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::CritAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the lcrit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_lcrit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::LowCritAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::LowCritAlarm)
+            .await
+    }
+
+    /// Returns whether any of this sensor's alarm, max alarm or crit alarm is asserted.
+    /// Alarms not supported by this sensor are treated as not asserted.
+    async fn overcurrent(&self) -> Result<bool> {
+        Ok(optional(self.read_alarm().await)?.unwrap_or(false)
+            || optional(self.read_max_alarm().await)?.unwrap_or(false)
+            || optional(self.read_crit_alarm().await)?.unwrap_or(false))
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -121,8 +130,17 @@ pub trait AsyncCurrentSensor: AsyncSensor<Value = Current> + std::fmt::Debug {
 pub(crate) struct CurrentSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl CurrentSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
+#[async_trait]
 impl AsyncSensor for CurrentSensorStruct {
     type Value = Current;
 
@@ -137,6 +155,24 @@ impl AsyncSensor for CurrentSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    async fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .await
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 #[async_trait]
@@ -147,6 +183,8 @@ impl AsyncParseable for CurrentSensorStruct {
         let curr = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(curr, SensorSubFunctionType::Input).await
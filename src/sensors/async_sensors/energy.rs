@@ -11,10 +11,10 @@ use std::path::{Path, PathBuf};
 /// Helper trait that sums up all functionality of a read-only energy sensor.
 pub trait AsyncEnergySensor: AsyncSensor<Value = Energy> + std::fmt::Debug {
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw().await? != 0)
     }
 
     /// Reads the input subfunction of this sensor.
@@ -29,8 +29,17 @@ pub trait AsyncEnergySensor: AsyncSensor<Value = Energy> + std::fmt::Debug {
 pub(crate) struct EnergySensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
 }
 
+impl EnergySensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
+}
+
+#[async_trait]
 impl AsyncSensor for EnergySensorStruct {
     type Value = Energy;
 
@@ -45,6 +54,24 @@ impl AsyncSensor for EnergySensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    async fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .await
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 #[async_trait]
@@ -55,6 +82,8 @@ impl AsyncParseable for EnergySensorStruct {
         let energy = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(energy, SensorSubFunctionType::Input).await
@@ -68,7 +97,11 @@ impl AsyncParseable for EnergySensorStruct {
 impl AsyncEnergySensor for EnergySensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl AsyncWriteableSensor for EnergySensorStruct {}
+impl AsyncWriteableSensor for EnergySensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Enable
+    }
+}
 
 #[cfg(feature = "writeable")]
 #[async_trait]
@@ -80,6 +113,23 @@ pub trait AsyncWriteableEnergySensor: AsyncEnergySensor + AsyncWriteableSensor {
         self.write_raw(SensorSubFunctionType::Enable, &enable.to_raw())
             .await
     }
+
+    /// Resets this sensor's energy accumulator by writing 0 to its input subfunction.
+    /// Returns `SubtypeNotSupported`, if the input subfunction is not writeable, because most
+    /// chips don't allow resetting the accumulator.
+    async fn reset(&self) -> Result<()> {
+        let path = self.subfunction_path(SensorSubFunctionType::Input);
+
+        match tokio::fs::OpenOptions::new().write(true).open(&path).await {
+            Ok(_) => self.write_raw(SensorSubFunctionType::Input, "0").await,
+            Err(e) => match e.kind() {
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::PermissionDenied => {
+                    Err(Error::subtype_not_supported(SensorSubFunctionType::Input))
+                }
+                _ => Err(Error::write(e, path)),
+            },
+        }
+    }
 }
 
 #[cfg(feature = "writeable")]
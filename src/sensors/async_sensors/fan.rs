@@ -3,8 +3,12 @@
 use super::*;
 use crate::hwmon::async_hwmon::Hwmon;
 use crate::parsing::{AsyncParseable, Result as ParsingResult};
+use crate::sensors::{optional, FanHealth, FanSpeedWarning};
 use crate::units::{AngularVelocity, FanDivisor, Raw};
 
+#[cfg(feature = "writeable")]
+use crate::units::PwmEnable;
+
 use std::path::{Path, PathBuf};
 
 #[async_trait]
@@ -27,10 +31,10 @@ pub trait AsyncFanSensor: AsyncSensor<Value = AngularVelocity> + std::fmt::Debug
     }
 
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw().await? != 0)
     }
 
     /// Reads the input subfunction of this temp sensor.
@@ -68,22 +72,22 @@ pub trait AsyncFanSensor: AsyncSensor<Value = AngularVelocity> + std::fmt::Debug
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the min subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_min_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MinAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MinAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the max subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_max_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MaxAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MaxAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -92,6 +96,63 @@ pub trait AsyncFanSensor: AsyncSensor<Value = AngularVelocity> + std::fmt::Debug
         let raw = self.read_raw(SensorSubFunctionType::Beep).await?;
         bool::from_raw(&raw).map_err(Error::from)
     }
+
+    /// Returns whether this fan runs outside of the speed range given by its min and max
+    /// subfunctions, based on the min_alarm and max_alarm subfunctions.
+    /// If an alarm subfunction is not supported, the input is compared to the corresponding limit
+    /// instead. Limits this sensor doesn't support are not checked.
+    /// Returns an error, if one of the involved subfunctions can't be read.
+    async fn speed_warning(&self) -> Result<Option<FanSpeedWarning>> {
+        let too_slow = match optional(self.read_min_alarm().await)? {
+            Some(alarm) => alarm,
+            None => match optional(self.read_min().await)? {
+                Some(min) => self.read_input().await? < min,
+                None => false,
+            },
+        };
+
+        if too_slow {
+            return Ok(Some(FanSpeedWarning::TooSlow));
+        }
+
+        let too_fast = match optional(self.read_max_alarm().await)? {
+            Some(alarm) => alarm,
+            None => match optional(self.read_max().await)? {
+                Some(max) => self.read_input().await? > max,
+                None => false,
+            },
+        };
+
+        Ok(too_fast.then_some(FanSpeedWarning::TooFast))
+    }
+
+    /// Combines this fan's fault, stall and alarm state into a single health value.
+    /// The fan counts as stalled, if it doesn't spin although its min or target speed is above zero.
+    /// Subfunctions this sensor doesn't support are not taken into account.
+    /// Returns an error, if the input or one of the supported subfunctions can't be read.
+    async fn health(&self) -> Result<FanHealth> {
+        if optional(self.read_faulty().await)?.unwrap_or(false) {
+            return Ok(FanHealth::Failed);
+        }
+
+        let zero = AngularVelocity::from_raw("0")?;
+        let input = self.read_input().await?;
+        let min = optional(self.read_min().await)?;
+        let target = optional(self.read_target().await)?;
+        let stalled = input == zero
+            && (min.is_some_and(|min| min > zero) || target.is_some_and(|target| target > zero));
+
+        let alarm = optional(self.read_alarm().await)?.unwrap_or(false)
+            || optional(self.read_min_alarm().await)?.unwrap_or(false);
+
+        if stalled {
+            Ok(FanHealth::Failed)
+        } else if alarm {
+            Ok(FanHealth::Degraded)
+        } else {
+            Ok(FanHealth::Ok)
+        }
+    }
 }
 
 /// Struct that represents a read only fan sensor.
@@ -99,8 +160,17 @@ pub trait AsyncFanSensor: AsyncSensor<Value = AngularVelocity> + std::fmt::Debug
 pub(crate) struct FanSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
 }
 
+impl FanSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
+}
+
+#[async_trait]
 impl AsyncSensor for FanSensorStruct {
     type Value = AngularVelocity;
 
@@ -115,6 +185,24 @@ impl AsyncSensor for FanSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    async fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .await
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 #[async_trait]
@@ -125,6 +213,8 @@ impl AsyncParseable for FanSensorStruct {
         let fan = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(fan, SensorSubFunctionType::Input).await
@@ -138,7 +228,11 @@ impl AsyncParseable for FanSensorStruct {
 impl AsyncFanSensor for FanSensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl AsyncWriteableSensor for FanSensorStruct {}
+impl AsyncWriteableSensor for FanSensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Min
+    }
+}
 
 #[cfg(feature = "writeable")]
 #[async_trait]
@@ -153,9 +247,36 @@ pub trait AsyncWriteableFanSensor: AsyncFanSensor + AsyncWriteableSensor {
             .await
     }
 
+    /// Writes target to this fan's target subfunction after checking that the pwm with the same index
+    /// is in closed-loop (automatic) mode, since chips silently reject targets otherwise.
+    /// If the pwm's mode can't be discovered, the target is written without checking.
+    /// Returns an error, if the pwm is not in closed-loop mode or this sensor doesn't support the subfunction.
+    async fn set_target_checked(&self, target: AngularVelocity) -> Result<()> {
+        let enable_path = self
+            .hwmon_path()
+            .join(format!("pwm{}_enable", self.index()));
+
+        match tokio::fs::read_to_string(&enable_path).await {
+            Ok(raw) => match PwmEnable::from_raw(raw.trim())? {
+                PwmEnable::BiosControl => (),
+                pwm_enable => return Err(Error::closed_loop_required(pwm_enable)),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => (),
+            Err(e) => return Err(Error::read(e, enable_path)),
+        }
+
+        self.write_target(target).await
+    }
+
     /// Converts div and writes it to this fan's divisor subfunction.
-    /// Returns an error, if this sensor doesn't support the subfunction.
+    /// Only the divisors 1 to 8 accepted by most chips are written, see `FanDivisor::try_new`.
+    /// Some chips like nct6775 and it87 also accept divisors from 16 to 128, which this refuses.
+    /// Use `write_raw` with `SensorSubFunctionType::Div` to write those.
+    /// Returns an error, if div is refused or this sensor doesn't support the subfunction.
     async fn write_div(&self, div: FanDivisor) -> Result<()> {
+        FanDivisor::try_new(div.as_u8())
+            .map_err(|e| Error::unit(e, self.subfunction_path(SensorSubFunctionType::Div)))?;
+
         self.write_raw(SensorSubFunctionType::Div, &div.to_raw())
             .await
     }
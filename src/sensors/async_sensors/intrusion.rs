@@ -12,8 +12,8 @@ pub trait AsyncIntrusionSensor: AsyncSensor<Value = bool> + std::fmt::Debug {
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -29,8 +29,17 @@ pub trait AsyncIntrusionSensor: AsyncSensor<Value = bool> + std::fmt::Debug {
 pub(crate) struct IntrusionSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
 }
 
+impl IntrusionSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
+}
+
+#[async_trait]
 impl AsyncSensor for IntrusionSensorStruct {
     type Value = bool;
 
@@ -45,6 +54,24 @@ impl AsyncSensor for IntrusionSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    async fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .await
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 #[async_trait]
@@ -55,6 +82,8 @@ impl AsyncParseable for IntrusionSensorStruct {
         let intrusion = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(intrusion, SensorSubFunctionType::Alarm).await
@@ -68,7 +97,11 @@ impl AsyncParseable for IntrusionSensorStruct {
 impl AsyncIntrusionSensor for IntrusionSensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl AsyncWriteableSensor for IntrusionSensorStruct {}
+impl AsyncWriteableSensor for IntrusionSensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Alarm
+    }
+}
 
 #[cfg(feature = "writeable")]
 #[async_trait]
@@ -0,0 +1,103 @@
+//! Module containing the sensor poller which reads sensors in a background task.
+
+use super::*;
+
+use std::fmt::Debug;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// Polls a set of registered sensors in a background task and publishes their latest inputs.
+///
+/// Every registered sensor gets its own [`watch::Receiver`] that always holds the most recent
+/// input of the sensor or `None`, if the last read failed or no read happened yet.
+/// This decouples the polling cadence from the cadence of the consumers.
+#[derive(Debug)]
+pub struct SensorPoller {
+    interval: Duration,
+    jobs: Vec<Box<dyn PollJob>>,
+}
+
+impl SensorPoller {
+    /// Creates a new `SensorPoller` that polls its sensors every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            jobs: Vec::new(),
+        }
+    }
+
+    /// Registers the given sensor and returns a receiver for its latest input.
+    pub fn register<S>(&mut self, sensor: S) -> watch::Receiver<Option<S::Value>>
+    where
+        S: AsyncSensor + Debug + Send + Sync + 'static,
+        S::Value: Debug + Send + Sync + 'static,
+    {
+        let (sender, receiver) = watch::channel(None);
+
+        self.jobs.push(Box::new(SensorJob { sensor, sender }));
+
+        receiver
+    }
+
+    /// Spawns the background task polling all registered sensors.
+    /// The task stops once all receivers have been dropped.
+    /// This function must be called from within a tokio runtime.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.interval);
+
+            loop {
+                interval.tick().await;
+
+                let mut any_receivers = false;
+
+                for job in &self.jobs {
+                    any_receivers |= job.poll().await;
+                }
+
+                if !any_receivers {
+                    break;
+                }
+            }
+        })
+    }
+}
+
+#[async_trait]
+trait PollJob: Debug + Send + Sync {
+    /// Reads the sensor and publishes the result.
+    /// Returns false, if nobody is listening anymore.
+    async fn poll(&self) -> bool;
+}
+
+#[derive(Debug)]
+struct SensorJob<S: AsyncSensor> {
+    sensor: S,
+    sender: watch::Sender<Option<S::Value>>,
+}
+
+#[async_trait]
+impl<S> PollJob for SensorJob<S>
+where
+    S: AsyncSensor + Debug + Send + Sync,
+    S::Value: Debug + Send + Sync,
+{
+    async fn poll(&self) -> bool {
+        if self.sender.is_closed() {
+            return false;
+        }
+
+        let value = self
+            .sensor
+            .read_input_debug()
+            .await
+            .ok()
+            .map(|(_, value)| value);
+
+        self.sender.send_replace(value);
+
+        true
+    }
+}
@@ -4,7 +4,8 @@ use super::*;
 
 use crate::hwmon::async_hwmon::Hwmon;
 use crate::parsing::{AsyncParseable, Result as ParsingResult};
-use crate::units::{Power, Ratio, Raw};
+use crate::sensors::optional;
+use crate::units::{Power, Ratio, Raw, RawInteger};
 
 use std::time::Duration;
 
@@ -46,6 +47,19 @@ pub trait AsyncPowerSensor: AsyncSensor<Value = Power> + std::fmt::Debug {
         Power::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the cap and cap_hyst subfunctions of this power sensor and returns the power level
+    /// `cap - cap_hyst` below which a cap alarm is cleared.
+    /// cap_hyst is interpreted as the width of the band below cap and the result is clamped at 0.
+    /// Returns an error, if this sensor doesn't support one of the subfunctions.
+    async fn cap_clear_level(&self) -> Result<Power> {
+        let cap = self.read_cap().await?;
+        let cap_hyst = self.read_cap_hyst().await?;
+
+        let clear_level = (cap.to_raw_integer() - cap_hyst.to_raw_integer()).max(0);
+
+        Ok(Power::try_from_raw_integer(clear_level)?)
+    }
+
     /// Reads the average_interval subfunction of this power sensor.
     /// Returns an error, if this sensor doesn't support the subfunction.
     async fn read_average_interval(&self) -> Result<Duration> {
@@ -73,6 +87,14 @@ pub trait AsyncPowerSensor: AsyncSensor<Value = Power> + std::fmt::Debug {
         Duration::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the max_interval subfunction of this power sensor.
+    /// This is the time window the max subfunction applies to.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn read_max_interval(&self) -> Result<Duration> {
+        let raw = self.read_raw(SensorSubFunctionType::MaxInterval).await?;
+        Duration::from_raw(&raw).map_err(Error::from)
+    }
+
     /// Reads the average_highest subfunction of this power sensor.
     /// Returns an error, if this sensor doesn't support the subfunction.
     async fn read_average_highest(&self) -> Result<Power> {
@@ -102,10 +124,10 @@ pub trait AsyncPowerSensor: AsyncSensor<Value = Power> + std::fmt::Debug {
     }
 
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw().await? != 0)
     }
 
     /// Reads the input subfunction of this sensor.
@@ -150,25 +172,56 @@ pub trait AsyncPowerSensor: AsyncSensor<Value = Power> + std::fmt::Debug {
         Self::Value::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the input_highest subfunction of this power sensor, which some chips use to track
+    /// the highest input since the history was last reset.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn read_input_highest(&self) -> Result<Power> {
+        let raw = self.read_raw(SensorSubFunctionType::InputHighest).await?;
+        Power::from_raw(&raw).map_err(Error::from)
+    }
+
+    /// Reads the input_lowest subfunction of this power sensor, which some chips use to track
+    /// the lowest input since the history was last reset.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn read_input_lowest(&self) -> Result<Power> {
+        let raw = self.read_raw(SensorSubFunctionType::InputLowest).await?;
+        Power::from_raw(&raw).map_err(Error::from)
+    }
+
+    /// Returns the power limit this chip actually enforces, which is the lower of the cap and
+    /// crit subfunctions.
+    /// Returns `Ok(None)`, if this sensor supports neither subfunction.
+    /// Returns an error, if a supported limit can't be read.
+    async fn effective_limit(&self) -> Result<Option<Power>> {
+        let cap = optional(self.read_cap().await)?;
+        let crit = optional(self.read_crit().await)?;
+
+        Ok(match (cap, crit) {
+            (Some(cap), Some(crit)) if crit < cap => Some(crit),
+            (Some(cap), _) => Some(cap),
+            (None, crit) => crit,
+        })
+    }
+
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the crit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_crit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::CritAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::CritAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the cap subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_cap_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::CapAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::CapAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -184,8 +237,17 @@ pub trait AsyncPowerSensor: AsyncSensor<Value = Power> + std::fmt::Debug {
 pub(crate) struct PowerSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl PowerSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
+#[async_trait]
 impl AsyncSensor for PowerSensorStruct {
     type Value = Power;
 
@@ -200,6 +262,24 @@ impl AsyncSensor for PowerSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    async fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .await
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 #[async_trait]
@@ -210,6 +290,8 @@ impl AsyncParseable for PowerSensorStruct {
         let power = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(power, SensorSubFunctionType::Input).await
@@ -223,7 +305,11 @@ impl AsyncParseable for PowerSensorStruct {
 impl AsyncPowerSensor for PowerSensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl AsyncWriteableSensor for PowerSensorStruct {}
+impl AsyncWriteableSensor for PowerSensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Cap
+    }
+}
 
 #[cfg(feature = "writeable")]
 #[async_trait]
@@ -250,6 +336,34 @@ pub trait AsyncWriteablePowerSensor: AsyncPowerSensor + AsyncWriteableSensor {
             .await
     }
 
+    /// Converts interval and writes it to the max_interval subfunction of this power sensor.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn write_max_interval(&self, interval: Duration) -> Result<()> {
+        self.write_raw(SensorSubFunctionType::MaxInterval, &interval.to_raw())
+            .await
+    }
+
+    /// Clamps interval to the range given by the average_interval_min and average_interval_max
+    /// subfunctions and writes it to the average_interval subfunction of this power sensor.
+    /// Bounds that this sensor doesn't expose are not applied.
+    /// Returns the interval that was actually written.
+    /// Returns an error, if this sensor doesn't support the average_interval subfunction.
+    async fn set_average_interval_clamped(&self, interval: Duration) -> Result<Duration> {
+        let mut interval = interval;
+
+        if let Ok(min) = self.read_average_interval_min().await {
+            interval = interval.max(min);
+        }
+
+        if let Ok(max) = self.read_average_interval_max().await {
+            interval = interval.min(max);
+        }
+
+        self.write_average_interval(interval).await?;
+
+        Ok(interval)
+    }
+
     /// Sets this sensor's enabled state.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn write_enable(&self, enable: bool) -> Result<()> {
@@ -2,9 +2,17 @@
 
 use super::*;
 use crate::parsing::{AsyncParseable, Result as ParsingResult};
+#[cfg(feature = "writeable")]
+use crate::sensors::ClampInfo;
+use crate::sensors::{optional, PwmState};
 use crate::units::{Frequency, Pwm, PwmEnable, PwmMode, Raw};
 
 use std::path::Path;
+#[cfg(feature = "writeable")]
+use std::time::Duration;
+
+#[cfg(feature = "writeable")]
+use tokio::task::JoinHandle;
 
 #[async_trait]
 /// Helper trait that sums up all functionality of a read-only pwm sensor.
@@ -23,6 +31,18 @@ pub trait AsyncPwmSensor: AsyncSensor<Value = Pwm> + std::fmt::Debug {
         PwmEnable::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Returns whether this pwm is under manual control, i.e. its enable subfunction is `ManualControl`.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn is_under_manual_control(&self) -> Result<bool> {
+        Ok(self.read_enable().await? == PwmEnable::ManualControl)
+    }
+
+    /// Returns whether this pwm runs at full speed, i.e. its enable subfunction is `FullSpeed`.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn is_full_speed(&self) -> Result<bool> {
+        Ok(self.read_enable().await? == PwmEnable::FullSpeed)
+    }
+
     /// Reads the mode subfunction of this pwm sensor.
     /// Returns an error, if this sensor doesn't support the subfunction.
     async fn read_mode(&self) -> Result<PwmMode> {
@@ -36,6 +56,49 @@ pub trait AsyncPwmSensor: AsyncSensor<Value = Pwm> + std::fmt::Debug {
         let raw = self.read_raw(SensorSubFunctionType::Freq).await?;
         Frequency::from_raw(&raw).map_err(Error::from)
     }
+
+    /// Reads the duty cycle of this pwm as a ratio between 0 and 1 alongside its frequency.
+    /// The frequency is `None`, if this sensor has no freq subfunction.
+    /// Returns an error, if this sensor's pwm subfunction can't be read.
+    async fn duty_and_frequency(&self) -> Result<(f64, Option<Frequency>)> {
+        let duty = f64::from(self.read_pwm().await?.as_u8()) / f64::from(u8::MAX);
+        let frequency = optional(self.read_frequency().await)?;
+
+        Ok((duty, frequency))
+    }
+
+    /// Returns the number of points of this pwm's auto fan curve (pwmN_auto_pointM_temp files).
+    /// Returns 0, if this pwm has no auto points.
+    async fn auto_point_count(&self) -> usize {
+        let mut count = 0;
+
+        loop {
+            let path = self.hwmon_path().join(format!(
+                "{}{}_auto_point{}_temp",
+                self.base(),
+                self.index(),
+                count + 1
+            ));
+
+            if tokio::fs::metadata(&path).await.is_err() {
+                return count;
+            }
+
+            count += 1;
+        }
+    }
+
+    /// Reads the pwm, enable, mode and freq subfunctions of this pwm sensor at once.
+    /// Subfunctions this sensor doesn't support are returned as `None`.
+    /// Returns an error, if a supported subfunction can't be read.
+    async fn read_state(&self) -> Result<PwmState> {
+        Ok(PwmState {
+            pwm: optional(self.read_pwm().await)?,
+            enable: optional(self.read_enable().await)?,
+            mode: optional(self.read_mode().await)?,
+            frequency: optional(self.read_frequency().await)?,
+        })
+    }
 }
 
 /// Struct that represents a read only pwm sensor.
@@ -43,8 +106,17 @@ pub trait AsyncPwmSensor: AsyncSensor<Value = Pwm> + std::fmt::Debug {
 pub(crate) struct PwmSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl PwmSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
+#[async_trait]
 impl AsyncSensor for PwmSensorStruct {
     type Value = Pwm;
 
@@ -59,6 +131,24 @@ impl AsyncSensor for PwmSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    async fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .await
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 #[async_trait]
@@ -69,6 +159,8 @@ impl AsyncParseable for PwmSensorStruct {
         let pwm = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(pwm, SensorSubFunctionType::Pwm).await
@@ -82,7 +174,11 @@ impl AsyncParseable for PwmSensorStruct {
 impl AsyncPwmSensor for PwmSensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl AsyncWriteableSensor for PwmSensorStruct {}
+impl AsyncWriteableSensor for PwmSensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Pwm
+    }
+}
 
 #[cfg(feature = "writeable")]
 #[async_trait]
@@ -95,6 +191,85 @@ pub trait AsyncWriteablePwmSensor: AsyncPwmSensor + AsyncWriteableSensor {
             .await
     }
 
+    /// Writes pwm to this pwm's pwm subfunction and reads it back to find out whether the chip
+    /// clamped or rounded the value.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn write_pwm_clamped_info(&self, pwm: Pwm) -> Result<ClampInfo> {
+        self.write_pwm(pwm).await?;
+        let stored = self.read_pwm().await?;
+
+        Ok(ClampInfo::new(pwm, stored))
+    }
+
+    /// Switches this pwm to manual control and writes pwm to its pwm subfunction for the
+    /// duration of `revert_after`. Afterwards a background task restores the pwm and enable
+    /// values this sensor had before, even if the returned handle is dropped.
+    /// The raw enable value is restored, so vendor specific automatic modes are kept.
+    /// This function must be called from within a tokio runtime.
+    /// Returns an error, if the prior state can't be read or the override can't be written.
+    /// If the override can't be written, the prior enable value is restored before returning.
+    /// The revert always attempts to restore both values, the first error is returned by the
+    /// handle.
+    async fn write_pwm_temporary(
+        &self,
+        pwm: Pwm,
+        revert_after: Duration,
+    ) -> Result<JoinHandle<Result<()>>>
+    where
+        Self: Clone + Send + Sync + 'static,
+    {
+        let prior_pwm = self.read_pwm().await?;
+        // The raw value is kept, because `PwmEnable` folds vendor specific automatic modes above 2
+        // into `BiosControl`.
+        let prior_enable = optional(self.read_enable_raw().await)?;
+
+        let switched = prior_enable
+            .is_some_and(|enable| enable.to_string() != PwmEnable::ManualControl.to_raw());
+
+        if switched {
+            self.write_enable(PwmEnable::ManualControl).await?;
+        }
+        if let Err(e) = self.write_pwm(pwm).await {
+            // The override failed, so hand control back instead of leaving the pwm in manual mode.
+            if let Some(enable) = prior_enable.filter(|_| switched) {
+                let _ = self
+                    .write_raw(SensorSubFunctionType::Enable, &enable.to_string())
+                    .await;
+            }
+
+            return Err(e);
+        }
+
+        let sensor = self.clone();
+
+        Ok(tokio::spawn(async move {
+            tokio::time::sleep(revert_after).await;
+
+            let pwm_result = sensor.write_pwm(prior_pwm).await;
+            let enable_result = match prior_enable {
+                Some(enable) => {
+                    sensor
+                        .write_raw(SensorSubFunctionType::Enable, &enable.to_string())
+                        .await
+                }
+                None => Ok(()),
+            };
+
+            pwm_result.and(enable_result)
+        }))
+    }
+
+    /// Writes pwm to this pwm's pwm subfunction but never below floor, so fans can't be set to a
+    /// speed at which they stall. A pwm of exactly 0 is written as is to allow stopping the fan.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn write_pwm_floored(&self, pwm: Pwm, floor: Pwm) -> Result<()> {
+        if pwm.as_u8() == 0 {
+            self.write_pwm(pwm).await
+        } else {
+            self.write_pwm(pwm.max(floor)).await
+        }
+    }
+
     /// Converts enable and writes it to this pwm's enable subfunction.
     /// Returns an error, if this sensor doesn't support the subfunction.
     async fn write_enable(&self, enable: PwmEnable) -> Result<()> {
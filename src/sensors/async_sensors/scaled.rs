@@ -0,0 +1,92 @@
+//! Module containing temp sensors of chips that don't report in millidegrees celsius.
+
+use super::temp::AsyncTempSensor;
+use super::*;
+use crate::sensors::is_temperature;
+use crate::units::Error as UnitError;
+
+use std::path::Path;
+
+/// The number of raw units per degree celsius the hwmon ABI mandates for temperatures.
+const MILLIDEGREES_PER_DEGREE: i64 = 1000;
+
+/// Wrapper around a temp sensor whose chip reports temperatures in a different scale than the
+/// millidegrees celsius the hwmon ABI mandates, which would otherwise yield wrong readings.
+/// All temperature subfunctions are rescaled to millidegrees before they are converted.
+/// Alarms, labels and other subfunctions are read unchanged.
+///
+/// # Known broken drivers
+/// No driver in the mainline kernel is currently known to report temperatures in whole degrees.
+/// Out-of-tree and vendor drivers occasionally do. If you come across one, please report it
+/// together with the chip's name, so it can be listed here.
+#[derive(Debug, Clone)]
+pub struct AsyncScaledTempSensor<S> {
+    sensor: S,
+    divisor: i64,
+}
+
+impl<S> AsyncScaledTempSensor<S> {
+    pub(crate) fn new(sensor: S, divisor: i32) -> Result<Self> {
+        if divisor <= 0 {
+            return Err(UnitError::invalid_value(divisor).into());
+        }
+
+        Ok(Self {
+            sensor,
+            divisor: i64::from(divisor),
+        })
+    }
+
+    /// Returns the wrapped sensor.
+    pub fn inner(&self) -> &S {
+        &self.sensor
+    }
+
+    /// Returns the number of raw units per degree celsius this sensor assumes.
+    pub fn divisor(&self) -> i32 {
+        i32::try_from(self.divisor).unwrap_or(i32::MAX)
+    }
+}
+
+#[async_trait]
+impl<S: AsyncSensor> AsyncSensor for AsyncScaledTempSensor<S> {
+    type Value = S::Value;
+
+    fn base(&self) -> &'static str {
+        self.sensor.base()
+    }
+
+    fn index(&self) -> u16 {
+        self.sensor.index()
+    }
+
+    fn hwmon_path(&self) -> &Path {
+        self.sensor.hwmon_path()
+    }
+
+    fn alarm_active_low(&self) -> bool {
+        self.sensor.alarm_active_low()
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.sensor.set_alarm_polarity(active_low);
+    }
+
+    async fn name(&self) -> String {
+        self.sensor.name().await
+    }
+
+    async fn read_raw(&self, sub_type: SensorSubFunctionType) -> Result<String> {
+        let raw = self.sensor.read_raw(sub_type).await?;
+
+        if !is_temperature(sub_type) {
+            return Ok(raw);
+        }
+
+        let value = raw.parse::<i64>().map_err(UnitError::parsing)?;
+
+        Ok((value * MILLIDEGREES_PER_DEGREE / self.divisor).to_string())
+    }
+}
+
+impl<S: AsyncTempSensor> AsyncTempSensor for AsyncScaledTempSensor<S> {}
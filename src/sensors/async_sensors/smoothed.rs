@@ -0,0 +1,114 @@
+//! Module containing temp sensors whose input is smoothed by an exponentially weighted moving average.
+
+use super::temp::AsyncTempSensor;
+use super::*;
+use crate::units::{Error as UnitError, Temperature};
+
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Wrapper around a temp sensor whose `read_input` returns an exponentially weighted moving
+/// average of all readings taken through it, smoothing noisy sensors for display.
+/// Every reading moves the average towards the new value by the fraction `alpha`.
+/// The unsmoothed input is still available through `read_unsmoothed_input` and `read_raw`.
+#[derive(Debug)]
+pub struct AsyncSmoothedTempSensor<S> {
+    sensor: S,
+    alpha: f64,
+    average: Mutex<Option<f64>>,
+}
+
+impl<S> AsyncSmoothedTempSensor<S> {
+    pub(crate) fn new(sensor: S, alpha: f64) -> Result<Self> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(UnitError::invalid_value(alpha).into());
+        }
+
+        Ok(Self {
+            sensor,
+            alpha,
+            average: Mutex::new(None),
+        })
+    }
+
+    /// Returns the wrapped sensor.
+    pub fn inner(&self) -> &S {
+        &self.sensor
+    }
+
+    /// Returns the smoothing factor of this sensor.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Forgets all previous readings, so the next reading starts a new average.
+    pub fn reset(&self) {
+        *self.average.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    fn update(&self, millidegrees: f64) -> f64 {
+        let mut average = self.average.lock().unwrap_or_else(|e| e.into_inner());
+
+        let updated = match *average {
+            Some(previous) => previous + self.alpha * (millidegrees - previous),
+            None => millidegrees,
+        };
+        *average = Some(updated);
+
+        updated
+    }
+}
+
+impl<S: AsyncTempSensor> AsyncSmoothedTempSensor<S> {
+    /// Reads the input subfunction of the wrapped sensor without smoothing it
+    /// or updating the average.
+    /// Returns an error, if this sensor doesn't support the subtype.
+    pub async fn read_unsmoothed_input(&self) -> Result<Temperature> {
+        self.sensor.read_input().await
+    }
+}
+
+#[async_trait]
+impl<S: AsyncSensor> AsyncSensor for AsyncSmoothedTempSensor<S> {
+    type Value = S::Value;
+
+    fn base(&self) -> &'static str {
+        self.sensor.base()
+    }
+
+    fn index(&self) -> u16 {
+        self.sensor.index()
+    }
+
+    fn hwmon_path(&self) -> &Path {
+        self.sensor.hwmon_path()
+    }
+
+    fn alarm_active_low(&self) -> bool {
+        self.sensor.alarm_active_low()
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.sensor.set_alarm_polarity(active_low);
+    }
+
+    async fn name(&self) -> String {
+        self.sensor.name().await
+    }
+
+    async fn read_raw(&self, sub_type: SensorSubFunctionType) -> Result<String> {
+        self.sensor.read_raw(sub_type).await
+    }
+}
+
+#[async_trait]
+impl<S: AsyncTempSensor> AsyncTempSensor for AsyncSmoothedTempSensor<S> {
+    async fn read_input(&self) -> Result<Temperature> {
+        let input = self.sensor.read_input().await?;
+        let millidegrees = input.to_raw().parse::<i64>().map_err(UnitError::parsing)?;
+
+        let average = self.update(millidegrees as f64);
+
+        Temperature::from_raw(&format!("{:.0}", average)).map_err(Error::from)
+    }
+}
@@ -1,9 +1,18 @@
 //! Module containing the temp sensors and their related functionality.
 
+use super::scaled::AsyncScaledTempSensor;
+use super::smoothed::AsyncSmoothedTempSensor;
 use super::*;
 use crate::hwmon::async_hwmon::Hwmon;
 use crate::parsing::{AsyncParseable, Result as ParsingResult};
-use crate::units::{Raw, TempType, Temperature};
+use crate::sensors::thermal_zone::{thermal_zone_path, trip_point_paths, CRITICAL_TRIP_TYPE};
+use crate::sensors::{optional, BurstStats, LimitStatus, TempReadings};
+use crate::units::{Error as UnitError, Raw, RawInteger, TempType, Temperature};
+
+#[cfg(feature = "writeable")]
+use crate::sensors::check_ordering;
+#[cfg(feature = "writeable")]
+use crate::units::parse_lenient_celsius;
 
 use std::path::{Path, PathBuf};
 
@@ -74,10 +83,10 @@ pub trait AsyncTempSensor: AsyncSensor<Value = Temperature> + std::fmt::Debug {
     }
 
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw().await? != 0)
     }
 
     /// Reads the input subfunction of this temp sensor.
@@ -91,6 +100,110 @@ pub trait AsyncTempSensor: AsyncSensor<Value = Temperature> + std::fmt::Debug {
         Temperature::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the input subfunction of this temp sensor for chips that store it as a 16 bit two's
+    /// complement value but report it unsigned, so sub-zero temperatures show up as values above
+    /// 32767 (e.g. "65036" for -0.5°C).
+    /// Only use this for drivers known to have this bug: it limits the range to ±32.767°C and
+    /// misreads correct inputs above that.
+    /// Returns an error, if this sensor doesn't support the subtype or the input doesn't fit 16 bits.
+    async fn read_input_signed16(&self) -> Result<Temperature> {
+        let raw = self.read_raw(SensorSubFunctionType::Input).await?;
+        let unsigned = raw.parse::<u16>().map_err(UnitError::parsing)?;
+
+        Ok(Temperature::from_raw(&(unsigned as i16).to_string())?)
+    }
+
+    /// Reads the input of this sensor and compares it to the min, max and crit subfunctions.
+    /// Limits this sensor doesn't support are not checked.
+    /// This is the single call a gauge needs to pick its color.
+    /// Returns an error, if the input or one of the supported limits can't be read.
+    async fn read_input_status(&self) -> Result<(Temperature, LimitStatus)> {
+        let input = self.read_input().await?;
+        let min = optional(self.read_min().await)?;
+        let max = optional(self.read_max().await)?;
+        let crit = optional(self.read_crit().await)?;
+
+        Ok((input, LimitStatus::classify(&input, min, max, crit)))
+    }
+
+    /// Returns the most severe threshold of this sensor in the order emergency, crit and max.
+    /// This is the single temperature a UI should present as the one to avoid.
+    /// Returns `Ok(None)`, if this sensor supports none of these subfunctions.
+    /// Returns an error, if a supported threshold can't be read.
+    async fn danger_threshold(&self) -> Result<Option<Temperature>> {
+        if let Some(emergency) = optional(self.read_emergency().await)? {
+            return Ok(Some(emergency));
+        }
+        if let Some(crit) = optional(self.read_crit().await)? {
+            return Ok(Some(crit));
+        }
+
+        optional(self.read_max().await)
+    }
+
+    /// Polls this sensor's input every `interval` until `window` consecutive readings lie within
+    /// `tolerance` of each other and returns their average.
+    /// This is useful for waiting until a sensor has warmed up.
+    /// Returns `Unstable`, if the readings didn't stabilize within `max_attempts` readings.
+    /// Returns an error, if the input can't be read.
+    async fn read_stable(
+        &self,
+        tolerance: Temperature,
+        window: usize,
+        interval: std::time::Duration,
+        max_attempts: usize,
+    ) -> Result<Temperature> {
+        let window = window.max(1);
+        let mut readings = std::collections::VecDeque::with_capacity(window);
+
+        for attempt in 0..max_attempts {
+            if attempt > 0 {
+                tokio::time::sleep(interval).await;
+            }
+
+            readings.push_back(self.read_input().await?.to_raw_integer());
+
+            if readings.len() > window {
+                readings.pop_front();
+            }
+
+            if readings.len() == window {
+                let min = readings.iter().min().copied().unwrap_or_default();
+                let max = readings.iter().max().copied().unwrap_or_default();
+
+                if max - min <= tolerance.to_raw_integer() {
+                    let sum: i64 = readings.iter().sum();
+                    return Ok(Temperature::try_from_raw_integer(sum / window as i64)?);
+                }
+            }
+        }
+
+        Err(Error::unstable(max_attempts))
+    }
+
+    /// Reads this sensor's input `count` times with `interval` in between and returns statistics
+    /// over the readings. This is useful to find out how noisy a sensor is.
+    /// Returns an error, if the input can't be read.
+    async fn read_burst_stats(
+        &self,
+        count: usize,
+        interval: std::time::Duration,
+    ) -> Result<BurstStats> {
+        let count = count.max(1);
+        let mut readings = Vec::with_capacity(count);
+
+        for i in 0..count {
+            if i > 0 {
+                tokio::time::sleep(interval).await;
+            }
+
+            readings.push(self.read_input().await?);
+        }
+
+        BurstStats::from_readings(&readings)
+            .ok_or_else(|| Error::subtype_not_supported(SensorSubFunctionType::Input))
+    }
+
     /// Reads this sensor's min value.
     /// Returns an error, if this sensor doesn't support the feature.
     async fn read_min(&self) -> Result<Self::Value> {
@@ -112,6 +225,80 @@ pub trait AsyncTempSensor: AsyncSensor<Value = Temperature> + std::fmt::Debug {
         Self::Value::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the min and min_hyst subfunctions of this temp sensor and returns the absolute
+    /// temperature `min + min_hyst` above which a min alarm is cleared.
+    /// Unlike [`read_min_hyst`](Self::read_min_hyst), which returns the raw min_hyst value,
+    /// min_hyst is interpreted as the width of the band above min here.
+    /// Returns an error, if this sensor doesn't support one of the subfunctions.
+    async fn min_hyst_absolute(&self) -> Result<Temperature> {
+        let min = self.read_min().await?;
+        let min_hyst = self.read_min_hyst().await?;
+
+        Ok(Temperature::try_from_raw_integer(
+            min.to_raw_integer() + min_hyst.to_raw_integer(),
+        )?)
+    }
+
+    /// Reads the crit and crit_hyst subfunctions of this temp sensor and returns the critical
+    /// hysteresis band as `(crit - crit_hyst, crit)`.
+    /// crit_hyst is interpreted as the width of the band below crit.
+    /// Returns an error, if this sensor doesn't support one of the subfunctions.
+    async fn crit_band(&self) -> Result<(Temperature, Temperature)> {
+        let crit = self.read_crit().await?;
+        let crit_hyst = self.read_crit_hyst().await?;
+
+        let low =
+            Temperature::try_from_raw_integer(crit.to_raw_integer() - crit_hyst.to_raw_integer())?;
+
+        Ok((low, crit))
+    }
+
+    /// Returns the thermal headroom `crit - input` of this temp sensor or `max - input`, if the
+    /// sensor has no crit subfunction.
+    /// Negative values indicate that the limit is exceeded.
+    /// Returns an error, if this sensor supports neither crit nor max or its input can't be read.
+    async fn headroom(&self) -> Result<Temperature> {
+        let limit = match self.read_crit().await {
+            Err(Error::SubtypeNotSupported { .. }) => self.read_max().await?,
+            limit => limit?,
+        };
+        let input = self.read_input().await?;
+
+        Ok(Temperature::try_from_raw_integer(
+            limit.to_raw_integer() - input.to_raw_integer(),
+        )?)
+    }
+
+    /// Reads the critical trip point of the thermal zone this sensor's hwmon belongs to.
+    /// This recovers the crit value on ACPI thermal setups whose hwmon lacks a crit subfunction.
+    /// Returns `Ok(None)`, if the hwmon belongs to no thermal zone or the zone has no critical trip point.
+    /// Returns an error, if the trip points of the zone can't be read.
+    async fn read_crit_from_thermal_zone(&self) -> Result<Option<Temperature>> {
+        let Some(zone) = thermal_zone_path(self.hwmon_path()) else {
+            return Ok(None);
+        };
+
+        for index in 0.. {
+            let (type_path, temp_path) = trip_point_paths(&zone, index);
+
+            let trip_type = match tokio::fs::read_to_string(&type_path).await {
+                Ok(trip_type) => trip_type,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => break,
+                Err(e) => return Err(Error::read(e, type_path)),
+            };
+
+            if trip_type.trim() == CRITICAL_TRIP_TYPE {
+                let raw = tokio::fs::read_to_string(&temp_path)
+                    .await
+                    .map_err(|e| Error::read(e, &temp_path))?;
+
+                return Ok(Some(Temperature::from_raw(&raw)?));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Reads whether this sensor is faulty or not.
     /// Returns an error, if this sensor doesn't support the feature.
     async fn read_faulty(&self) -> Result<bool> {
@@ -122,43 +309,43 @@ pub trait AsyncTempSensor: AsyncSensor<Value = Temperature> + std::fmt::Debug {
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the min subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_min_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MinAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MinAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the max subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_max_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MaxAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MaxAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the crit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_crit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::CritAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::CritAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the lcrit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_lcrit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::LowCritAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::LowCritAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the emergency subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_emergency_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::EmergencyAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::EmergencyAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -167,6 +354,54 @@ pub trait AsyncTempSensor: AsyncSensor<Value = Temperature> + std::fmt::Debug {
         let raw = self.read_raw(SensorSubFunctionType::Beep).await?;
         bool::from_raw(&raw).map_err(Error::from)
     }
+
+    /// Reads every subfunction of this temp sensor independently and returns all results,
+    /// so that a diagnostic dump can show exactly which subfunctions work and which don't.
+    async fn read_all_fields(&self) -> TempReadings {
+        TempReadings {
+            input: self.read_input().await,
+            min: self.read_min().await,
+            max: self.read_max().await,
+            crit: self.read_crit().await,
+            lcrit: self.read_lcrit().await,
+            emergency: self.read_emergency().await,
+            min_hyst: self.read_min_hyst().await,
+            max_hyst: self.read_max_hyst().await,
+            crit_hyst: self.read_crit_hyst().await,
+            offset: self.read_offset().await,
+            temp_type: self.read_type().await,
+            enable: self.read_enable().await,
+            faulty: self.read_faulty().await,
+            alarm: self.read_alarm().await,
+            min_alarm: self.read_min_alarm().await,
+            max_alarm: self.read_max_alarm().await,
+            crit_alarm: self.read_crit_alarm().await,
+            lcrit_alarm: self.read_lcrit_alarm().await,
+            emergency_alarm: self.read_emergency_alarm().await,
+        }
+    }
+
+    /// Returns a wrapper around this sensor whose `read_input` returns an exponentially weighted
+    /// moving average of its readings with the smoothing factor `alpha`.
+    /// Higher values of `alpha` follow the input more closely, `1.0` disables smoothing.
+    /// Returns an error, if `alpha` is not in the range (0, 1].
+    fn into_smoothed(self, alpha: f64) -> Result<AsyncSmoothedTempSensor<Self>>
+    where
+        Self: Sized,
+    {
+        AsyncSmoothedTempSensor::new(self, alpha)
+    }
+
+    /// Returns a wrapper around this sensor for chips that report temperatures in `divisor` raw
+    /// units per degree celsius instead of the millidegrees the hwmon ABI mandates,
+    /// e.g. `1` for chips reporting whole degrees.
+    /// Returns an error, if `divisor` is not positive.
+    fn with_scale(self, divisor: i32) -> Result<AsyncScaledTempSensor<Self>>
+    where
+        Self: Sized,
+    {
+        AsyncScaledTempSensor::new(self, divisor)
+    }
 }
 
 /// Struct that represents a read only temp sensor.
@@ -174,8 +409,17 @@ pub trait AsyncTempSensor: AsyncSensor<Value = Temperature> + std::fmt::Debug {
 pub(crate) struct TempSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl TempSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
+#[async_trait]
 impl AsyncSensor for TempSensorStruct {
     type Value = Temperature;
 
@@ -190,6 +434,24 @@ impl AsyncSensor for TempSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    async fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .await
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 #[async_trait]
@@ -200,6 +462,8 @@ impl AsyncParseable for TempSensorStruct {
         let temp = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(temp, SensorSubFunctionType::Input).await
@@ -299,6 +563,36 @@ pub trait AsyncWriteableTempSensor: AsyncTempSensor + AsyncWriteableSensor {
             .await
     }
 
+    /// Writes the given min, max and crit values to this sensor.
+    /// Before anything is written, it is checked that min <= max <= crit for all given values.
+    /// Returns an error, if the values are not ordered or this sensor doesn't support one of the subfunctions.
+    async fn write_limits(
+        &self,
+        min: Option<Temperature>,
+        max: Option<Temperature>,
+        crit: Option<Temperature>,
+    ) -> Result<()> {
+        check_ordering(&[
+            (SensorSubFunctionType::Min, min),
+            (SensorSubFunctionType::Max, max),
+            (SensorSubFunctionType::Crit, crit),
+        ])?;
+
+        if let Some(min) = min {
+            self.write_min(min).await?;
+        }
+
+        if let Some(max) = max {
+            self.write_max(max).await?;
+        }
+
+        if let Some(crit) = crit {
+            self.write_crit(crit).await?;
+        }
+
+        Ok(())
+    }
+
     /// Writes this sensor's crit value.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn write_crit(&self, crit: Self::Value) -> Result<()> {
@@ -312,6 +606,32 @@ pub trait AsyncWriteableTempSensor: AsyncTempSensor + AsyncWriteableSensor {
         self.write_raw(SensorSubFunctionType::Beep, &beep.to_raw())
             .await
     }
+
+    /// Parses a human entered temperature like "85C" or "185F" and writes it to the given
+    /// threshold subfunction of this sensor.
+    /// Values without a unit suffix are interpreted as degrees celsius.
+    /// Returns an error, if sub is not a temperature threshold, the string can't be parsed or
+    /// the sensor doesn't support the subfunction.
+    async fn write_threshold_str(&self, sub: SensorSubFunctionType, value: &str) -> Result<()> {
+        match sub {
+            SensorSubFunctionType::Min
+            | SensorSubFunctionType::Max
+            | SensorSubFunctionType::Crit
+            | SensorSubFunctionType::LowCrit
+            | SensorSubFunctionType::Emergency
+            | SensorSubFunctionType::MinHyst
+            | SensorSubFunctionType::MaxHyst
+            | SensorSubFunctionType::CritHyst
+            | SensorSubFunctionType::LowCritHyst
+            | SensorSubFunctionType::EmergencyHyst => {}
+            _ => return Err(Error::subtype_not_supported(sub)),
+        }
+
+        let celsius = parse_lenient_celsius(value)?;
+        let temp = Temperature::from_raw(&format!("{:.0}", celsius * 1000.0))?;
+
+        self.write_raw(sub, &temp.to_raw()).await
+    }
 }
 
 #[cfg(feature = "writeable")]
@@ -0,0 +1,91 @@
+//! Module containing sensors whose input is transformed before unit conversion.
+
+use super::*;
+use super::{
+    curr::AsyncCurrentSensor, energy::AsyncEnergySensor, fan::AsyncFanSensor,
+    humidity::AsyncHumiditySensor, intrusion::AsyncIntrusionSensor, power::AsyncPowerSensor,
+    pwm::AsyncPwmSensor, temp::AsyncTempSensor, voltage::AsyncVoltageSensor,
+};
+use crate::units::Error as UnitError;
+
+use std::path::Path;
+
+/// Wrapper around a sensor that applies a transform to the raw integer value of its input
+/// before it is converted to the sensor's unit.
+/// This can be used to correct known offsets or scaling errors of a chip.
+/// All other subfunctions are read unchanged.
+#[derive(Debug, Clone)]
+pub struct AsyncTransformedSensor<S> {
+    sensor: S,
+    transform: fn(i64) -> i64,
+}
+
+impl<S> AsyncTransformedSensor<S> {
+    pub(crate) fn new(sensor: S, transform: fn(i64) -> i64) -> Self {
+        Self { sensor, transform }
+    }
+
+    /// Returns the wrapped sensor.
+    pub fn inner(&self) -> &S {
+        &self.sensor
+    }
+}
+
+#[async_trait]
+impl<S: AsyncSensor> AsyncSensor for AsyncTransformedSensor<S> {
+    type Value = S::Value;
+
+    fn base(&self) -> &'static str {
+        self.sensor.base()
+    }
+
+    fn index(&self) -> u16 {
+        self.sensor.index()
+    }
+
+    fn hwmon_path(&self) -> &Path {
+        self.sensor.hwmon_path()
+    }
+
+    fn alarm_active_low(&self) -> bool {
+        self.sensor.alarm_active_low()
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.sensor.set_alarm_polarity(active_low);
+    }
+
+    async fn name(&self) -> String {
+        self.sensor.name().await
+    }
+
+    async fn read_raw(&self, sub_type: SensorSubFunctionType) -> Result<String> {
+        let raw = self.sensor.read_raw(sub_type).await?;
+
+        if sub_type != SensorSubFunctionType::Input {
+            return Ok(raw);
+        }
+
+        let value = raw.parse::<i64>().map_err(UnitError::parsing)?;
+
+        Ok((self.transform)(value).to_string())
+    }
+}
+
+impl<S: AsyncCurrentSensor> AsyncCurrentSensor for AsyncTransformedSensor<S> {}
+
+impl<S: AsyncEnergySensor> AsyncEnergySensor for AsyncTransformedSensor<S> {}
+
+impl<S: AsyncFanSensor> AsyncFanSensor for AsyncTransformedSensor<S> {}
+
+impl<S: AsyncHumiditySensor> AsyncHumiditySensor for AsyncTransformedSensor<S> {}
+
+impl<S: AsyncIntrusionSensor> AsyncIntrusionSensor for AsyncTransformedSensor<S> {}
+
+impl<S: AsyncPowerSensor> AsyncPowerSensor for AsyncTransformedSensor<S> {}
+
+impl<S: AsyncPwmSensor> AsyncPwmSensor for AsyncTransformedSensor<S> {}
+
+impl<S: AsyncTempSensor> AsyncTempSensor for AsyncTransformedSensor<S> {}
+
+impl<S: AsyncVoltageSensor> AsyncVoltageSensor for AsyncTransformedSensor<S> {}
@@ -3,18 +3,22 @@
 use super::*;
 use crate::hwmon::async_hwmon::Hwmon;
 use crate::parsing::{AsyncParseable, Result as ParsingResult};
-use crate::units::Voltage;
+use crate::units::{Error as UnitError, RawInteger, Voltage};
+
+#[cfg(feature = "writeable")]
+use crate::sensors::check_ordering;
 
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[async_trait]
 /// Helper trait that sums up all functionality of a read-only voltage sensor.
 pub trait AsyncVoltageSensor: AsyncSensor<Value = Voltage> + std::fmt::Debug {
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw().await? != 0)
     }
 
     /// Reads the input subfunction of this sensor.
@@ -24,6 +28,26 @@ pub trait AsyncVoltageSensor: AsyncSensor<Value = Voltage> + std::fmt::Debug {
         Self::Value::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the input subfunction of this sensor and multiplies it with the given multiplier.
+    /// This is a software correction for inputs behind a resistor divider that the driver doesn't
+    /// account for. The multiplier has to be derived from the board's schematics by the user.
+    /// Returns an error, if this sensor doesn't support the subtype, the multiplier is negative or not
+    /// finite or the scaled value can't be represented as a `Voltage`.
+    async fn read_input_scaled(&self, multiplier: f64) -> Result<Self::Value> {
+        if !multiplier.is_finite() || multiplier < 0.0 {
+            return Err(UnitError::invalid_value(multiplier).into());
+        }
+
+        let input = self.read_input().await?;
+        let scaled = (input.to_raw_integer() as f64 * multiplier).round();
+
+        if !(i64::MIN as f64..i64::MAX as f64).contains(&scaled) {
+            return Err(UnitError::invalid_value(scaled).into());
+        }
+
+        Ok(Voltage::try_from_raw_integer(scaled as i64)?)
+    }
+
     /// Reads this sensor's min value.
     /// Returns an error, if this sensor doesn't support the feature.
     async fn read_min(&self) -> Result<Self::Value> {
@@ -76,36 +100,36 @@ pub trait AsyncVoltageSensor: AsyncSensor<Value = Voltage> + std::fmt::Debug {
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the min subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_min_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MinAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MinAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the max subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_max_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MaxAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MaxAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the crit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_crit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::CritAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::CritAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition exists for the lcrit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn read_lcrit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::LowCritAlarm).await?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::LowCritAlarm)
+            .await
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -114,6 +138,15 @@ pub trait AsyncVoltageSensor: AsyncSensor<Value = Voltage> + std::fmt::Debug {
         let raw = self.read_raw(SensorSubFunctionType::Beep).await?;
         bool::from_raw(&raw).map_err(Error::from)
     }
+
+    /// Reads the average_interval subfunction of this voltage sensor.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn read_average_interval(&self) -> Result<Duration> {
+        let raw = self
+            .read_raw(SensorSubFunctionType::AverageInterval)
+            .await?;
+        Duration::from_raw(&raw).map_err(Error::from)
+    }
 }
 
 /// Struct that represents a read only voltage sensor.
@@ -121,8 +154,17 @@ pub trait AsyncVoltageSensor: AsyncSensor<Value = Voltage> + std::fmt::Debug {
 pub(crate) struct VoltageSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl VoltageSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
+#[async_trait]
 impl AsyncSensor for VoltageSensorStruct {
     type Value = Voltage;
 
@@ -137,6 +179,24 @@ impl AsyncSensor for VoltageSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    async fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .await
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 #[async_trait]
@@ -147,6 +207,8 @@ impl AsyncParseable for VoltageSensorStruct {
         let volt = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(volt, SensorSubFunctionType::Input).await
@@ -201,12 +263,74 @@ pub trait AsyncWriteableVoltageSensor: AsyncVoltageSensor + AsyncWriteableSensor
             .await
     }
 
+    /// Writes the given lcrit, min, max and crit values to this sensor.
+    /// Before anything is written, it is checked that lcrit <= min <= max <= crit.
+    /// Values that are `None` are not written, but their currently stored values are still
+    /// taken into account when checking the ordering, if they can be read.
+    /// Returns an error, if the values are not ordered or this sensor doesn't support one of the subfunctions.
+    async fn write_thresholds(
+        &self,
+        lcrit: Option<Voltage>,
+        min: Option<Voltage>,
+        max: Option<Voltage>,
+        crit: Option<Voltage>,
+    ) -> Result<()> {
+        let stored_lcrit = match lcrit {
+            Some(_) => None,
+            None => self.read_lcrit().await.ok(),
+        };
+        let stored_min = match min {
+            Some(_) => None,
+            None => self.read_min().await.ok(),
+        };
+        let stored_max = match max {
+            Some(_) => None,
+            None => self.read_max().await.ok(),
+        };
+        let stored_crit = match crit {
+            Some(_) => None,
+            None => self.read_crit().await.ok(),
+        };
+
+        check_ordering(&[
+            (SensorSubFunctionType::LowCrit, lcrit.or(stored_lcrit)),
+            (SensorSubFunctionType::Min, min.or(stored_min)),
+            (SensorSubFunctionType::Max, max.or(stored_max)),
+            (SensorSubFunctionType::Crit, crit.or(stored_crit)),
+        ])?;
+
+        if let Some(lcrit) = lcrit {
+            self.write_lcrit(lcrit).await?;
+        }
+
+        if let Some(min) = min {
+            self.write_min(min).await?;
+        }
+
+        if let Some(max) = max {
+            self.write_max(max).await?;
+        }
+
+        if let Some(crit) = crit {
+            self.write_crit(crit).await?;
+        }
+
+        Ok(())
+    }
+
     /// Sets whether or not an alarm condition for the sensor also triggers beeping.
     /// Returns an error, if the sensor doesn't support the feature.
     async fn write_beep(&self, beep: bool) -> Result<()> {
         self.write_raw(SensorSubFunctionType::Beep, &beep.to_raw())
             .await
     }
+
+    /// Converts interval and writes it to the average_interval subfunction of this voltage sensor.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    async fn write_average_interval(&self, interval: Duration) -> Result<()> {
+        self.write_raw(SensorSubFunctionType::AverageInterval, &interval.to_raw())
+            .await
+    }
 }
 
 #[cfg(feature = "writeable")]
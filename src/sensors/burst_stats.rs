@@ -0,0 +1,65 @@
+//! Module containing statistics over bursts of temperature readings.
+
+use crate::units::{RawInteger, Temperature};
+
+/// Statistics over a burst of temperature readings.
+/// They can be used to estimate how noisy a sensor is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BurstStats {
+    /// The lowest reading.
+    pub min: Temperature,
+    /// The highest reading.
+    pub max: Temperature,
+    /// The average of all readings.
+    pub mean: Temperature,
+    /// The standard deviation of all readings in degrees celsius.
+    pub std_dev: f64,
+}
+
+impl BurstStats {
+    /// Computes the statistics of the given readings.
+    /// Returns `None`, if `readings` is empty.
+    pub fn from_readings(readings: &[Temperature]) -> Option<Self> {
+        let min = *readings.iter().min_by_key(|r| r.to_raw_integer())?;
+        let max = *readings.iter().max_by_key(|r| r.to_raw_integer())?;
+
+        let count = readings.len() as f64;
+        let sum: i64 = readings.iter().map(RawInteger::to_raw_integer).sum();
+        let mean = sum as f64 / count;
+        let variance = readings
+            .iter()
+            .map(|r| (r.to_raw_integer() as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        Some(Self {
+            min,
+            max,
+            mean: Temperature::try_from_raw_integer(mean.round() as i64).ok()?,
+            std_dev: variance.sqrt() / 1000.0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Raw;
+
+    #[test]
+    fn test_from_readings() {
+        let readings: Vec<Temperature> = ["40000", "42000", "44000", "42000"]
+            .iter()
+            .map(|raw| Temperature::from_raw(raw).unwrap())
+            .collect();
+
+        let stats = BurstStats::from_readings(&readings).unwrap();
+
+        assert_eq!(Temperature::from_raw("40000").unwrap(), stats.min);
+        assert_eq!(Temperature::from_raw("44000").unwrap(), stats.max);
+        assert_eq!(Temperature::from_raw("42000").unwrap(), stats.mean);
+        assert!((stats.std_dev - 2.0f64.sqrt()).abs() < 0.001);
+
+        assert!(BurstStats::from_readings(&[]).is_none());
+    }
+}
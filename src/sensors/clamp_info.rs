@@ -0,0 +1,30 @@
+//! Module containing the result of a pwm write that the chip may have clamped.
+
+use crate::units::Pwm;
+
+/// The outcome of writing a pwm value that the chip may have clamped or rounded.
+/// This allows warning users like "requested 120, chip stored 128".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClampInfo {
+    /// The value that was written.
+    pub requested: Pwm,
+    /// The value the chip stored, as read back after writing.
+    pub stored: Pwm,
+    /// The stored value minus the requested value.
+    pub delta: i16,
+}
+
+impl ClampInfo {
+    pub(crate) fn new(requested: Pwm, stored: Pwm) -> Self {
+        Self {
+            requested,
+            stored,
+            delta: i16::from(stored.as_u8()) - i16::from(requested.as_u8()),
+        }
+    }
+
+    /// Returns whether the chip stored a different value than the requested one.
+    pub fn is_clamped(&self) -> bool {
+        self.delta != 0
+    }
+}
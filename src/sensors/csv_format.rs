@@ -0,0 +1,74 @@
+//! Helpers for formatting sensor readings as csv.
+
+use super::SensorSubFunctionType;
+
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Returns the header of the column holding the given subfunction of a sensor like `hwmon0/temp1_input`.
+pub(crate) fn column_name(
+    hwmon_path: &Path,
+    base: &str,
+    index: u16,
+    sub_type: SensorSubFunctionType,
+) -> String {
+    let hwmon = hwmon_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    format!("{}/{}{}{}", hwmon, base, index, sub_type.to_suffix())
+}
+
+/// Returns the given time as seconds since the unix epoch with millisecond precision.
+pub(crate) fn timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+    format!(
+        "{}.{:03}",
+        since_epoch.as_secs(),
+        since_epoch.subsec_millis()
+    )
+}
+
+/// Joins the given cells into a csv row, quoting cells that contain separators or quotes.
+pub(crate) fn row(cells: &[String]) -> String {
+    cells
+        .iter()
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    #[test]
+    fn test_row() {
+        let cells = [
+            "1.000".to_string(),
+            String::new(),
+            "a,b".to_string(),
+            "say \"hi\"".to_string(),
+        ];
+
+        assert_eq!(r#"1.000,,"a,b","say ""hi""""#, row(&cells));
+    }
+
+    #[test]
+    fn test_timestamp() {
+        assert_eq!(
+            "12.345",
+            timestamp(UNIX_EPOCH + Duration::from_millis(12_345))
+        );
+    }
+}
@@ -6,7 +6,7 @@ pub use std::{
 };
 
 use crate::sensors::SensorSubFunctionType;
-use crate::units::Error as UnitError;
+use crate::units::{Error as UnitError, PwmEnable};
 
 pub(super) type Result<T> = std::result::Result<T, Error>;
 
@@ -35,6 +35,14 @@ pub enum Error {
         source: UnitError,
     },
 
+    /// A UnitError occurred for the value of a subfunction.
+    Unit {
+        /// The source of the error.
+        source: UnitError,
+        /// The path of the subfunction.
+        path: PathBuf,
+    },
+
     /// You have insufficient rights. Try using the read only variant of whatever returned this error.
     InsufficientRights {
         /// The path where the error occurred.
@@ -52,6 +60,26 @@ pub enum Error {
 
     /// The sensor you tried to read from or write to is disabled.
     DisabledSensor,
+
+    /// The values you tried to write are not correctly ordered.
+    InvalidOrdering {
+        /// The subfunction whose value should be lower or equal.
+        lower: SensorSubFunctionType,
+        /// The subfunction whose value should be greater or equal.
+        upper: SensorSubFunctionType,
+    },
+
+    /// The fan's target can only be written while its pwm is in closed-loop (automatic) mode.
+    ClosedLoopRequired {
+        /// The current enable state of the fan's pwm.
+        pwm_enable: PwmEnable,
+    },
+
+    /// The sensor's readings didn't stabilize within the allowed number of attempts.
+    Unstable {
+        /// The number of readings taken.
+        attempts: usize,
+    },
 }
 
 impl Error {
@@ -70,6 +98,14 @@ impl Error {
         }
     }
 
+    #[cfg(feature = "writeable")]
+    pub(crate) fn unit(source: UnitError, path: impl Into<PathBuf>) -> Self {
+        Self::Unit {
+            source,
+            path: path.into(),
+        }
+    }
+
     pub(crate) fn insufficient_rights(path: impl Into<PathBuf>) -> Self {
         Self::InsufficientRights { path: path.into() }
     }
@@ -77,6 +113,23 @@ impl Error {
     pub(crate) fn subtype_not_supported(sub_type: SensorSubFunctionType) -> Self {
         Self::SubtypeNotSupported { sub_type }
     }
+
+    #[cfg(feature = "writeable")]
+    pub(crate) fn invalid_ordering(
+        lower: SensorSubFunctionType,
+        upper: SensorSubFunctionType,
+    ) -> Self {
+        Self::InvalidOrdering { lower, upper }
+    }
+
+    #[cfg(feature = "writeable")]
+    pub(crate) fn closed_loop_required(pwm_enable: PwmEnable) -> Self {
+        Self::ClosedLoopRequired { pwm_enable }
+    }
+
+    pub(crate) fn unstable(attempts: usize) -> Self {
+        Self::Unstable { attempts }
+    }
 }
 
 impl StdError for Error {
@@ -85,10 +138,14 @@ impl StdError for Error {
             Error::Read { source, .. } => Some(source),
             Error::Write { source, .. } => Some(source),
             Error::UnitError { source } => Some(source),
+            Error::Unit { source, .. } => Some(source),
             Error::InsufficientRights { .. } => None,
             Error::SubtypeNotSupported { .. } => None,
             Error::FaultySensor => None,
             Error::DisabledSensor => None,
+            Error::InvalidOrdering { .. } => None,
+            Error::ClosedLoopRequired { .. } => None,
+            Error::Unstable { .. } => None,
         }
     }
 }
@@ -109,6 +166,9 @@ impl Display for Error {
                 source
             ),
             Error::UnitError { source } => write!(f, "Raw sensor error: {}", source),
+            Error::Unit { source, path } => {
+                write!(f, "Invalid value for sensor at {}: {}", path.display(), source)
+            }
             Error::InsufficientRights { path } => write!(
                 f,
                 "You have insufficient rights to read/write {}",
@@ -119,6 +179,21 @@ impl Display for Error {
             }
             Error::FaultySensor => write!(f, "The sensor is faulty"),
             Error::DisabledSensor => write!(f, "The sensor is disabled"),
+            Error::InvalidOrdering { lower, upper } => write!(
+                f,
+                "The value for subtype {} must not be greater than the value for subtype {}",
+                lower, upper
+            ),
+            Error::ClosedLoopRequired { pwm_enable } => write!(
+                f,
+                "The fan's target can only be written in closed-loop mode but its pwm is in mode {}",
+                pwm_enable
+            ),
+            Error::Unstable { attempts } => write!(
+                f,
+                "The sensor's readings didn't stabilize within {} attempts",
+                attempts
+            ),
         }
     }
 }
@@ -0,0 +1,13 @@
+//! Module containing the overall health of a fan.
+
+/// The overall health of a fan, combining its fault, stall and alarm state into a single
+/// traffic light value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FanHealth {
+    /// The fan spins and no alarm is raised.
+    Ok,
+    /// The fan spins but an alarm is raised, e.g. because it runs below its min speed.
+    Degraded,
+    /// The fan is reported faulty or has stalled.
+    Failed,
+}
@@ -0,0 +1,10 @@
+//! Module containing warnings about fans running outside of their speed limits.
+
+/// Warning that a fan runs outside of the speed range given by its min and max subfunctions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FanSpeedWarning {
+    /// The fan runs slower than its min speed.
+    TooSlow,
+    /// The fan runs faster than its max speed.
+    TooFast,
+}
@@ -0,0 +1,35 @@
+//! Module containing the position of a sensor's input relative to its limits.
+
+/// The position of a sensor's input relative to the limits it exposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimitStatus {
+    /// The input lies within all readable limits.
+    Ok,
+    /// The input is above the max limit but not above the crit limit.
+    AboveMax,
+    /// The input is above the crit limit.
+    AboveCrit,
+    /// The input is below the min limit.
+    BelowMin,
+}
+
+impl LimitStatus {
+    /// Compares `input` to the given limits. Limits that are `None` are not checked.
+    /// The crit limit takes precedence over the max limit.
+    pub(crate) fn classify<T: PartialOrd>(
+        input: &T,
+        min: Option<T>,
+        max: Option<T>,
+        crit: Option<T>,
+    ) -> Self {
+        if crit.is_some_and(|crit| *input > crit) {
+            LimitStatus::AboveCrit
+        } else if max.is_some_and(|max| *input > max) {
+            LimitStatus::AboveMax
+        } else if min.is_some_and(|min| *input < min) {
+            LimitStatus::BelowMin
+        } else {
+            LimitStatus::Ok
+        }
+    }
+}
@@ -6,8 +6,82 @@ pub mod sync_sensors;
 #[cfg(feature = "async")]
 pub mod async_sensors;
 
+mod burst_stats;
+#[cfg(feature = "writeable")]
+mod clamp_info;
+mod csv_format;
 mod error;
+mod fan_health;
+mod fan_speed_warning;
+mod limit_status;
+mod pwm_state;
+mod sensor_kind;
 mod subfunction_type;
+mod temp_readings;
+mod thermal_zone;
 
+pub use burst_stats::BurstStats;
+#[cfg(feature = "writeable")]
+pub use clamp_info::ClampInfo;
 pub use error::Error;
+pub use fan_health::FanHealth;
+pub use fan_speed_warning::FanSpeedWarning;
+pub use limit_status::LimitStatus;
+pub use pwm_state::PwmState;
+pub use sensor_kind::SensorKind;
 pub use subfunction_type::SensorSubFunctionType;
+pub use temp_readings::TempReadings;
+
+/// Maps an error caused by an unsupported subfunction to `Ok(None)`.
+pub(crate) fn optional<T>(result: Result<T, Error>) -> Result<Option<T>, Error> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::SubtypeNotSupported { .. }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns whether the given subfunction of a temp sensor holds a temperature.
+pub(crate) fn is_temperature(sub_type: SensorSubFunctionType) -> bool {
+    matches!(
+        sub_type,
+        SensorSubFunctionType::Input
+            | SensorSubFunctionType::Lowest
+            | SensorSubFunctionType::Highest
+            | SensorSubFunctionType::InputLowest
+            | SensorSubFunctionType::InputHighest
+            | SensorSubFunctionType::Min
+            | SensorSubFunctionType::Max
+            | SensorSubFunctionType::Crit
+            | SensorSubFunctionType::LowCrit
+            | SensorSubFunctionType::Emergency
+            | SensorSubFunctionType::MinHyst
+            | SensorSubFunctionType::MaxHyst
+            | SensorSubFunctionType::CritHyst
+            | SensorSubFunctionType::LowCritHyst
+            | SensorSubFunctionType::EmergencyHyst
+            | SensorSubFunctionType::Offset
+    )
+}
+
+/// Checks that all given values are ordered ascendingly, ignoring the ones that are `None`.
+/// Returns an error naming the first pair of subfunctions that is out of order.
+#[cfg(feature = "writeable")]
+pub(crate) fn check_ordering<T: PartialOrd>(
+    values: &[(SensorSubFunctionType, Option<T>)],
+) -> Result<(), Error> {
+    let present: Vec<_> = values
+        .iter()
+        .filter_map(|(sub_type, value)| value.as_ref().map(|value| (*sub_type, value)))
+        .collect();
+
+    for (i, (lower_type, lower)) in present.iter().enumerate() {
+        for (upper_type, upper) in &present[i + 1..] {
+            if lower > upper {
+                return Err(Error::invalid_ordering(*lower_type, *upper_type));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+//! Module containing the combined state of a pwm sensor.
+
+use crate::units::{Frequency, Pwm, PwmEnable, PwmMode};
+
+/// Combined state of all subfunctions of a pwm sensor.
+/// Subfunctions the sensor doesn't support are `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PwmState {
+    /// The pwm subfunction.
+    pub pwm: Option<Pwm>,
+    /// The enable subfunction.
+    pub enable: Option<PwmEnable>,
+    /// The mode subfunction.
+    pub mode: Option<PwmMode>,
+    /// The freq subfunction.
+    pub frequency: Option<Frequency>,
+}
@@ -0,0 +1,118 @@
+//! Module containing sensor kinds.
+
+use std::fmt::{Display, Formatter, Result};
+
+/// Enum that represents the kind of a sensor.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SensorKind {
+    Current,
+    Energy,
+    Fan,
+    Humidity,
+    Intrusion,
+    Power,
+    Pwm,
+    Temp,
+    Voltage,
+}
+
+impl SensorKind {
+    /// Returns a slice containing all sensor kinds.
+    pub fn all() -> &'static [SensorKind] {
+        const ARRAY: [SensorKind; 9] = [
+            SensorKind::Current,
+            SensorKind::Energy,
+            SensorKind::Fan,
+            SensorKind::Humidity,
+            SensorKind::Intrusion,
+            SensorKind::Power,
+            SensorKind::Pwm,
+            SensorKind::Temp,
+            SensorKind::Voltage,
+        ];
+        &ARRAY
+    }
+
+    /// Returns the base of the sysfs file names of sensors of this kind (e.g. "in" for voltages).
+    pub fn base(self) -> &'static str {
+        match self {
+            SensorKind::Current => "curr",
+            SensorKind::Energy => "energy",
+            SensorKind::Fan => "fan",
+            SensorKind::Humidity => "humidity",
+            SensorKind::Intrusion => "intrusion",
+            SensorKind::Power => "power",
+            SensorKind::Pwm => "pwm",
+            SensorKind::Temp => "temp",
+            SensorKind::Voltage => "in",
+        }
+    }
+
+    /// Returns the index of the first sensor of this kind.
+    /// Voltages and intrusions start at index 0, all other sensors at index 1.
+    pub fn start_index(self) -> u16 {
+        match self {
+            SensorKind::Intrusion | SensorKind::Voltage => 0,
+            _ => 1,
+        }
+    }
+
+    /// Returns the sensor kind belonging to the given sysfs file name base if there is one.
+    pub fn from_base(base: &str) -> Option<SensorKind> {
+        Self::all().iter().copied().find(|kind| kind.base() == base)
+    }
+
+    /// Parses the name of a sensor's sysfs file like `temp1_input` or `pwm2` into the kind and index
+    /// of the sensor it belongs to.
+    pub(crate) fn parse_file_name(file_name: &str) -> Option<(SensorKind, u16)> {
+        let index_start = file_name.find(|c: char| c.is_ascii_digit())?;
+        let (base, rest) = file_name.split_at(index_start);
+        let index_end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (index, suffix) = rest.split_at(index_end);
+
+        if !suffix.is_empty() && !suffix.starts_with('_') {
+            return None;
+        }
+
+        Some((Self::from_base(base)?, index.parse().ok()?))
+    }
+}
+
+impl Display for SensorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.base())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_index() {
+        assert_eq!(0, SensorKind::Voltage.start_index());
+        assert_eq!(1, SensorKind::Temp.start_index());
+        assert_eq!(Some(SensorKind::Voltage), SensorKind::from_base("in"));
+    }
+
+    #[test]
+    fn test_parse_file_name() {
+        assert_eq!(
+            Some((SensorKind::Temp, 1)),
+            SensorKind::parse_file_name("temp1_input")
+        );
+        assert_eq!(
+            Some((SensorKind::Pwm, 12)),
+            SensorKind::parse_file_name("pwm12")
+        );
+        assert_eq!(
+            Some((SensorKind::Voltage, 0)),
+            SensorKind::parse_file_name("in0_max_alarm")
+        );
+        assert_eq!(None, SensorKind::parse_file_name("update_interval"));
+        assert_eq!(None, SensorKind::parse_file_name("temp1x_input"));
+    }
+}
@@ -38,6 +38,7 @@ pub enum SensorSubFunctionType {
     Pulses,
     Target,
     AverageInterval,
+    MaxInterval,
     AverageMax,
     AverageMin,
     Cap,
@@ -88,7 +89,7 @@ impl SensorSubFunctionType {
     }
 
     pub(crate) fn read_write_list() -> &'static [SensorSubFunctionType] {
-        const ARRAY: [SensorSubFunctionType; 25] = [
+        const ARRAY: [SensorSubFunctionType; 26] = [
             SensorSubFunctionType::Enable,
             SensorSubFunctionType::Max,
             SensorSubFunctionType::Min,
@@ -105,6 +106,7 @@ impl SensorSubFunctionType {
             SensorSubFunctionType::Pulses,
             SensorSubFunctionType::Target,
             SensorSubFunctionType::AverageInterval,
+            SensorSubFunctionType::MaxInterval,
             SensorSubFunctionType::AverageMax,
             SensorSubFunctionType::AverageMin,
             SensorSubFunctionType::Cap,
@@ -118,7 +120,6 @@ impl SensorSubFunctionType {
         &ARRAY
     }
 
-    #[cfg(feature = "writeable")]
     pub(crate) fn write_only_list() -> &'static [SensorSubFunctionType] {
         const ARRAY: [SensorSubFunctionType; 1] = [SensorSubFunctionType::ResetHistory];
         &ARRAY
@@ -131,6 +132,14 @@ impl SensorSubFunctionType {
             .copied()
     }
 
+    pub(crate) fn all_list() -> impl Iterator<Item = Self> {
+        Self::read_only_list()
+            .iter()
+            .chain(Self::read_write_list())
+            .chain(Self::write_only_list())
+            .copied()
+    }
+
     #[cfg(feature = "writeable")]
     pub(crate) fn write_list() -> impl Iterator<Item = Self> {
         Self::write_only_list()
@@ -173,6 +182,7 @@ impl SensorSubFunctionType {
             SensorSubFunctionType::Pulses => "_pulses",
             SensorSubFunctionType::Target => "_target",
             SensorSubFunctionType::AverageInterval => "_average_interval",
+            SensorSubFunctionType::MaxInterval => "_max_interval",
             SensorSubFunctionType::AverageMax => "_average_max",
             SensorSubFunctionType::AverageMin => "_average_min",
             SensorSubFunctionType::Cap => "_cap",
@@ -0,0 +1,266 @@
+//! Module containing a sensor of any kind and its related functionality.
+
+use super::*;
+use crate::sensors::sync_sensors::{
+    curr::*, energy::*, fan::*, humidity::*, intrusion::*, power::*, pwm::*, temp::*, voltage::*,
+};
+use crate::sensors::SensorKind;
+
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+enum Inner {
+    Current(CurrentSensorStruct),
+    Energy(EnergySensorStruct),
+    Fan(FanSensorStruct),
+    Humidity(HumiditySensorStruct),
+    Intrusion(IntrusionSensorStruct),
+    Power(PowerSensorStruct),
+    Pwm(PwmSensorStruct),
+    Temp(TempSensorStruct),
+    Voltage(VoltageSensorStruct),
+}
+
+/// A sensor of any kind.
+/// Use [`kind`](Self::kind) to find out which kind it is and the `as_*` methods to get access to
+/// the functionality of that kind.
+#[derive(Debug, Clone)]
+pub struct AnySensor(Inner);
+
+impl AnySensor {
+    /// Returns the kind of this sensor.
+    pub fn kind(&self) -> SensorKind {
+        match &self.0 {
+            Inner::Current(_) => SensorKind::Current,
+            Inner::Energy(_) => SensorKind::Energy,
+            Inner::Fan(_) => SensorKind::Fan,
+            Inner::Humidity(_) => SensorKind::Humidity,
+            Inner::Intrusion(_) => SensorKind::Intrusion,
+            Inner::Power(_) => SensorKind::Power,
+            Inner::Pwm(_) => SensorKind::Pwm,
+            Inner::Temp(_) => SensorKind::Temp,
+            Inner::Voltage(_) => SensorKind::Voltage,
+        }
+    }
+
+    /// Returns this sensor as a current sensor.
+    /// Returns `None`, if this sensor is not a current sensor.
+    pub fn as_current(&self) -> Option<&(impl CurrentSensor + Clone + Send + Sync)> {
+        match &self.0 {
+            Inner::Current(sensor) => Some(sensor),
+            _ => None,
+        }
+    }
+
+    /// Returns this sensor as a energy sensor.
+    /// Returns `None`, if this sensor is not a energy sensor.
+    pub fn as_energy(&self) -> Option<&(impl EnergySensor + Clone + Send + Sync)> {
+        match &self.0 {
+            Inner::Energy(sensor) => Some(sensor),
+            _ => None,
+        }
+    }
+
+    /// Returns this sensor as a fan sensor.
+    /// Returns `None`, if this sensor is not a fan sensor.
+    pub fn as_fan(&self) -> Option<&(impl FanSensor + Clone + Send + Sync)> {
+        match &self.0 {
+            Inner::Fan(sensor) => Some(sensor),
+            _ => None,
+        }
+    }
+
+    /// Returns this sensor as a humidity sensor.
+    /// Returns `None`, if this sensor is not a humidity sensor.
+    pub fn as_humidity(&self) -> Option<&(impl HumiditySensor + Clone + Send + Sync)> {
+        match &self.0 {
+            Inner::Humidity(sensor) => Some(sensor),
+            _ => None,
+        }
+    }
+
+    /// Returns this sensor as a intrusion sensor.
+    /// Returns `None`, if this sensor is not a intrusion sensor.
+    pub fn as_intrusion(&self) -> Option<&(impl IntrusionSensor + Clone + Send + Sync)> {
+        match &self.0 {
+            Inner::Intrusion(sensor) => Some(sensor),
+            _ => None,
+        }
+    }
+
+    /// Returns this sensor as a power sensor.
+    /// Returns `None`, if this sensor is not a power sensor.
+    pub fn as_power(&self) -> Option<&(impl PowerSensor + Clone + Send + Sync)> {
+        match &self.0 {
+            Inner::Power(sensor) => Some(sensor),
+            _ => None,
+        }
+    }
+
+    /// Returns this sensor as a pwm sensor.
+    /// Returns `None`, if this sensor is not a pwm sensor.
+    pub fn as_pwm(&self) -> Option<&(impl PwmSensor + Clone + Send + Sync)> {
+        match &self.0 {
+            Inner::Pwm(sensor) => Some(sensor),
+            _ => None,
+        }
+    }
+
+    /// Returns this sensor as a temp sensor.
+    /// Returns `None`, if this sensor is not a temp sensor.
+    pub fn as_temp(&self) -> Option<&(impl TempSensor + Clone + Send + Sync)> {
+        match &self.0 {
+            Inner::Temp(sensor) => Some(sensor),
+            _ => None,
+        }
+    }
+
+    /// Returns this sensor as a voltage sensor.
+    /// Returns `None`, if this sensor is not a voltage sensor.
+    pub fn as_voltage(&self) -> Option<&(impl VoltageSensor + Clone + Send + Sync)> {
+        match &self.0 {
+            Inner::Voltage(sensor) => Some(sensor),
+            _ => None,
+        }
+    }
+}
+
+impl From<CurrentSensorStruct> for AnySensor {
+    fn from(sensor: CurrentSensorStruct) -> Self {
+        Self(Inner::Current(sensor))
+    }
+}
+
+impl From<EnergySensorStruct> for AnySensor {
+    fn from(sensor: EnergySensorStruct) -> Self {
+        Self(Inner::Energy(sensor))
+    }
+}
+
+impl From<FanSensorStruct> for AnySensor {
+    fn from(sensor: FanSensorStruct) -> Self {
+        Self(Inner::Fan(sensor))
+    }
+}
+
+impl From<HumiditySensorStruct> for AnySensor {
+    fn from(sensor: HumiditySensorStruct) -> Self {
+        Self(Inner::Humidity(sensor))
+    }
+}
+
+impl From<IntrusionSensorStruct> for AnySensor {
+    fn from(sensor: IntrusionSensorStruct) -> Self {
+        Self(Inner::Intrusion(sensor))
+    }
+}
+
+impl From<PowerSensorStruct> for AnySensor {
+    fn from(sensor: PowerSensorStruct) -> Self {
+        Self(Inner::Power(sensor))
+    }
+}
+
+impl From<PwmSensorStruct> for AnySensor {
+    fn from(sensor: PwmSensorStruct) -> Self {
+        Self(Inner::Pwm(sensor))
+    }
+}
+
+impl From<TempSensorStruct> for AnySensor {
+    fn from(sensor: TempSensorStruct) -> Self {
+        Self(Inner::Temp(sensor))
+    }
+}
+
+impl From<VoltageSensorStruct> for AnySensor {
+    fn from(sensor: VoltageSensorStruct) -> Self {
+        Self(Inner::Voltage(sensor))
+    }
+}
+
+impl DynSensor for AnySensor {
+    fn base(&self) -> &'static str {
+        match &self.0 {
+            Inner::Current(sensor) => sensor.base(),
+            Inner::Energy(sensor) => sensor.base(),
+            Inner::Fan(sensor) => sensor.base(),
+            Inner::Humidity(sensor) => sensor.base(),
+            Inner::Intrusion(sensor) => sensor.base(),
+            Inner::Power(sensor) => sensor.base(),
+            Inner::Pwm(sensor) => sensor.base(),
+            Inner::Temp(sensor) => sensor.base(),
+            Inner::Voltage(sensor) => sensor.base(),
+        }
+    }
+
+    fn index(&self) -> u16 {
+        match &self.0 {
+            Inner::Current(sensor) => sensor.index(),
+            Inner::Energy(sensor) => sensor.index(),
+            Inner::Fan(sensor) => sensor.index(),
+            Inner::Humidity(sensor) => sensor.index(),
+            Inner::Intrusion(sensor) => sensor.index(),
+            Inner::Power(sensor) => sensor.index(),
+            Inner::Pwm(sensor) => sensor.index(),
+            Inner::Temp(sensor) => sensor.index(),
+            Inner::Voltage(sensor) => sensor.index(),
+        }
+    }
+
+    fn hwmon_path(&self) -> &Path {
+        match &self.0 {
+            Inner::Current(sensor) => sensor.hwmon_path(),
+            Inner::Energy(sensor) => sensor.hwmon_path(),
+            Inner::Fan(sensor) => sensor.hwmon_path(),
+            Inner::Humidity(sensor) => sensor.hwmon_path(),
+            Inner::Intrusion(sensor) => sensor.hwmon_path(),
+            Inner::Power(sensor) => sensor.hwmon_path(),
+            Inner::Pwm(sensor) => sensor.hwmon_path(),
+            Inner::Temp(sensor) => sensor.hwmon_path(),
+            Inner::Voltage(sensor) => sensor.hwmon_path(),
+        }
+    }
+
+    fn name(&self) -> String {
+        match &self.0 {
+            Inner::Current(sensor) => sensor.name(),
+            Inner::Energy(sensor) => sensor.name(),
+            Inner::Fan(sensor) => sensor.name(),
+            Inner::Humidity(sensor) => sensor.name(),
+            Inner::Intrusion(sensor) => sensor.name(),
+            Inner::Power(sensor) => sensor.name(),
+            Inner::Pwm(sensor) => sensor.name(),
+            Inner::Temp(sensor) => sensor.name(),
+            Inner::Voltage(sensor) => sensor.name(),
+        }
+    }
+
+    fn read_input_raw(&self) -> Result<String> {
+        match &self.0 {
+            Inner::Current(sensor) => sensor.read_raw(SensorSubFunctionType::Input),
+            Inner::Energy(sensor) => sensor.read_raw(SensorSubFunctionType::Input),
+            Inner::Fan(sensor) => sensor.read_raw(SensorSubFunctionType::Input),
+            Inner::Humidity(sensor) => sensor.read_raw(SensorSubFunctionType::Input),
+            Inner::Intrusion(sensor) => sensor.read_raw(SensorSubFunctionType::Input),
+            Inner::Power(sensor) => sensor.read_raw(SensorSubFunctionType::Input),
+            Inner::Pwm(sensor) => sensor.read_raw(SensorSubFunctionType::Input),
+            Inner::Temp(sensor) => sensor.read_raw(SensorSubFunctionType::Input),
+            Inner::Voltage(sensor) => sensor.read_raw(SensorSubFunctionType::Input),
+        }
+    }
+
+    fn read_subfunction_raw(&self, sub_type: SensorSubFunctionType) -> Result<String> {
+        match &self.0 {
+            Inner::Current(sensor) => sensor.read_raw(sub_type),
+            Inner::Energy(sensor) => sensor.read_raw(sub_type),
+            Inner::Fan(sensor) => sensor.read_raw(sub_type),
+            Inner::Humidity(sensor) => sensor.read_raw(sub_type),
+            Inner::Intrusion(sensor) => sensor.read_raw(sub_type),
+            Inner::Power(sensor) => sensor.read_raw(sub_type),
+            Inner::Pwm(sensor) => sensor.read_raw(sub_type),
+            Inner::Temp(sensor) => sensor.read_raw(sub_type),
+            Inner::Voltage(sensor) => sensor.read_raw(sub_type),
+        }
+    }
+}
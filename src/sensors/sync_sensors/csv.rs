@@ -0,0 +1,70 @@
+//! Module containing a logger that appends sensor readings to a csv file.
+
+use super::DynSensor;
+use crate::sensors::csv_format::{column_name, row, timestamp};
+use crate::sensors::SensorSubFunctionType;
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Logger that appends the readings of a set of sensors to a csv file.
+/// Every row starts with a timestamp in seconds since the unix epoch, followed by one cell per
+/// sensor and subfunction. Readings that fail produce empty cells.
+#[derive(Debug)]
+pub struct CsvLogger {
+    file: File,
+    sensors: Vec<Box<dyn DynSensor>>,
+    subfunctions: Vec<SensorSubFunctionType>,
+}
+
+impl CsvLogger {
+    /// Opens the csv file at `path` for appending, creating it if it doesn't exist.
+    /// A header naming every column like `hwmon0/temp1_input` is written, if the file is empty.
+    /// Returns an error, if the file can't be opened or written.
+    pub fn new(
+        path: impl AsRef<Path>,
+        sensors: Vec<Box<dyn DynSensor>>,
+        subfunctions: &[SensorSubFunctionType],
+    ) -> io::Result<Self> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        if file.metadata()?.len() == 0 {
+            let mut header = vec!["timestamp".to_string()];
+
+            for sensor in &sensors {
+                for &sub_type in subfunctions {
+                    header.push(column_name(
+                        sensor.hwmon_path(),
+                        sensor.base(),
+                        sensor.index(),
+                        sub_type,
+                    ));
+                }
+            }
+
+            writeln!(file, "{}", row(&header))?;
+        }
+
+        Ok(Self {
+            file,
+            sensors,
+            subfunctions: subfunctions.to_vec(),
+        })
+    }
+
+    /// Reads all configured sensors and appends a timestamped row to the file.
+    /// Returns an error, if the file can't be written.
+    pub fn append(&mut self) -> io::Result<()> {
+        let mut cells = vec![timestamp(SystemTime::now())];
+
+        for sensor in &self.sensors {
+            for &sub_type in &self.subfunctions {
+                cells.push(sensor.read_subfunction_raw(sub_type).unwrap_or_default());
+            }
+        }
+
+        writeln!(self.file, "{}", row(&cells))
+    }
+}
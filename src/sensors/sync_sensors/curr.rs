@@ -3,6 +3,7 @@
 use super::*;
 use crate::hwmon::sync_hwmon::Hwmon;
 use crate::parsing::{Parseable, Result as ParsingResult};
+use crate::sensors::optional;
 use crate::units::Current;
 
 #[cfg(feature = "writeable")]
@@ -11,10 +12,10 @@ use std::path::{Path, PathBuf};
 /// Helper trait that sums up all functionality of a read-only current sensor.
 pub trait CurrentSensor: Sensor<Value = Current> + std::fmt::Debug {
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw()? != 0)
     }
 
     /// Reads the input subfunction of this sensor.
@@ -76,36 +77,39 @@ pub trait CurrentSensor: Sensor<Value = Current> + std::fmt::Debug {
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
     }
 
     /// Reads whether or not an alarm condition exists for the min subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_min_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MinAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MinAlarm)
     }
 
     /// Reads whether or not an alarm condition exists for the max subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_max_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MaxAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MaxAlarm)
     }
 
     /// Reads whether or not an alarm condition exists for the crit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_crit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::CritAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::CritAlarm)
     }
 
     /// Reads whether or not an alarm condition exists for the lcrit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_lcrit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::LowCritAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::LowCritAlarm)
+    }
+
+    /// Returns whether any of this sensor's alarm, max alarm or crit alarm is asserted.
+    /// Alarms not supported by this sensor are treated as not asserted.
+    fn overcurrent(&self) -> Result<bool> {
+        Ok(optional(self.read_alarm())?.unwrap_or(false)
+            || optional(self.read_max_alarm())?.unwrap_or(false)
+            || optional(self.read_crit_alarm())?.unwrap_or(false))
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -120,6 +124,14 @@ pub trait CurrentSensor: Sensor<Value = Current> + std::fmt::Debug {
 pub(crate) struct CurrentSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl CurrentSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
 impl Sensor for CurrentSensorStruct {
@@ -136,6 +148,23 @@ impl Sensor for CurrentSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 impl Parseable for CurrentSensorStruct {
@@ -145,6 +174,8 @@ impl Parseable for CurrentSensorStruct {
         let curr = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(curr, SensorSubFunctionType::Input)
@@ -10,10 +10,10 @@ use std::path::{Path, PathBuf};
 /// Helper trait that sums up all functionality of a read-only energy sensor.
 pub trait EnergySensor: Sensor<Value = Energy> + std::fmt::Debug {
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw()? != 0)
     }
 
     /// Reads the input subfunction of this sensor.
@@ -28,6 +28,14 @@ pub trait EnergySensor: Sensor<Value = Energy> + std::fmt::Debug {
 pub(crate) struct EnergySensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl EnergySensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
 impl Sensor for EnergySensorStruct {
@@ -44,6 +52,23 @@ impl Sensor for EnergySensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 impl Parseable for EnergySensorStruct {
@@ -53,6 +78,8 @@ impl Parseable for EnergySensorStruct {
         let energy = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(energy, SensorSubFunctionType::Input)
@@ -66,7 +93,11 @@ impl Parseable for EnergySensorStruct {
 impl EnergySensor for EnergySensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl WriteableSensor for EnergySensorStruct {}
+impl WriteableSensor for EnergySensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Enable
+    }
+}
 
 #[cfg(feature = "writeable")]
 /// Helper trait that sums up all functionality of a read-write energy sensor.
@@ -76,6 +107,23 @@ pub trait WriteableEnergySensor: EnergySensor + WriteableSensor {
     fn write_enable(&self, enable: bool) -> Result<()> {
         self.write_raw(SensorSubFunctionType::Enable, &enable.to_raw())
     }
+
+    /// Resets this sensor's energy accumulator by writing 0 to its input subfunction.
+    /// Returns `SubtypeNotSupported`, if the input subfunction is not writeable, because most
+    /// chips don't allow resetting the accumulator.
+    fn reset(&self) -> Result<()> {
+        let path = self.subfunction_path(SensorSubFunctionType::Input);
+
+        match std::fs::OpenOptions::new().write(true).open(&path) {
+            Ok(_) => self.write_raw(SensorSubFunctionType::Input, "0"),
+            Err(e) => match e.kind() {
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::PermissionDenied => {
+                    Err(Error::subtype_not_supported(SensorSubFunctionType::Input))
+                }
+                _ => Err(Error::write(e, path)),
+            },
+        }
+    }
 }
 
 #[cfg(feature = "writeable")]
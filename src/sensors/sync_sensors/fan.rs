@@ -3,8 +3,12 @@
 use super::*;
 use crate::hwmon::sync_hwmon::Hwmon;
 use crate::parsing::{Parseable, Result as ParsingResult};
+use crate::sensors::{optional, FanHealth, FanSpeedWarning};
 use crate::units::{AngularVelocity, FanDivisor, Raw};
 
+#[cfg(feature = "writeable")]
+use crate::units::PwmEnable;
+
 use std::path::{Path, PathBuf};
 
 /// Helper trait that sums up all functionality of a read-only fan sensor.
@@ -26,10 +30,10 @@ pub trait FanSensor: Sensor<Value = AngularVelocity> + std::fmt::Debug {
     }
 
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw()? != 0)
     }
 
     /// Reads the input subfunction of this temp sensor.
@@ -67,22 +71,19 @@ pub trait FanSensor: Sensor<Value = AngularVelocity> + std::fmt::Debug {
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
     }
 
     /// Reads whether or not an alarm condition exists for the min subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_min_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MinAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MinAlarm)
     }
 
     /// Reads whether or not an alarm condition exists for the max subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_max_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MaxAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MaxAlarm)
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -91,6 +92,63 @@ pub trait FanSensor: Sensor<Value = AngularVelocity> + std::fmt::Debug {
         let raw = self.read_raw(SensorSubFunctionType::Beep)?;
         bool::from_raw(&raw).map_err(Error::from)
     }
+
+    /// Returns whether this fan runs outside of the speed range given by its min and max
+    /// subfunctions, based on the min_alarm and max_alarm subfunctions.
+    /// If an alarm subfunction is not supported, the input is compared to the corresponding limit
+    /// instead. Limits this sensor doesn't support are not checked.
+    /// Returns an error, if one of the involved subfunctions can't be read.
+    fn speed_warning(&self) -> Result<Option<FanSpeedWarning>> {
+        let too_slow = match optional(self.read_min_alarm())? {
+            Some(alarm) => alarm,
+            None => match optional(self.read_min())? {
+                Some(min) => self.read_input()? < min,
+                None => false,
+            },
+        };
+
+        if too_slow {
+            return Ok(Some(FanSpeedWarning::TooSlow));
+        }
+
+        let too_fast = match optional(self.read_max_alarm())? {
+            Some(alarm) => alarm,
+            None => match optional(self.read_max())? {
+                Some(max) => self.read_input()? > max,
+                None => false,
+            },
+        };
+
+        Ok(too_fast.then_some(FanSpeedWarning::TooFast))
+    }
+
+    /// Combines this fan's fault, stall and alarm state into a single health value.
+    /// The fan counts as stalled, if it doesn't spin although its min or target speed is above zero.
+    /// Subfunctions this sensor doesn't support are not taken into account.
+    /// Returns an error, if the input or one of the supported subfunctions can't be read.
+    fn health(&self) -> Result<FanHealth> {
+        if optional(self.read_faulty())?.unwrap_or(false) {
+            return Ok(FanHealth::Failed);
+        }
+
+        let zero = AngularVelocity::from_raw("0")?;
+        let input = self.read_input()?;
+        let min = optional(self.read_min())?;
+        let target = optional(self.read_target())?;
+        let stalled = input == zero
+            && (min.is_some_and(|min| min > zero) || target.is_some_and(|target| target > zero));
+
+        let alarm = optional(self.read_alarm())?.unwrap_or(false)
+            || optional(self.read_min_alarm())?.unwrap_or(false);
+
+        if stalled {
+            Ok(FanHealth::Failed)
+        } else if alarm {
+            Ok(FanHealth::Degraded)
+        } else {
+            Ok(FanHealth::Ok)
+        }
+    }
 }
 
 /// Struct that represents a read only fan sensor.
@@ -98,6 +156,14 @@ pub trait FanSensor: Sensor<Value = AngularVelocity> + std::fmt::Debug {
 pub(crate) struct FanSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl FanSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
 impl Sensor for FanSensorStruct {
@@ -114,6 +180,23 @@ impl Sensor for FanSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 impl Parseable for FanSensorStruct {
@@ -123,6 +206,8 @@ impl Parseable for FanSensorStruct {
         let fan = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(fan, SensorSubFunctionType::Input)
@@ -136,7 +221,11 @@ impl Parseable for FanSensorStruct {
 impl FanSensor for FanSensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl WriteableSensor for FanSensorStruct {}
+impl WriteableSensor for FanSensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Min
+    }
+}
 
 #[cfg(feature = "writeable")]
 /// Helper trait that sums up all functionality of a read-write fan sensor.
@@ -149,9 +238,36 @@ pub trait WriteableFanSensor: FanSensor + WriteableSensor {
         self.write_raw(SensorSubFunctionType::Target, &target.to_raw())
     }
 
+    /// Writes target to this fan's target subfunction after checking that the pwm with the same index
+    /// is in closed-loop (automatic) mode, since chips silently reject targets otherwise.
+    /// If the pwm's mode can't be discovered, the target is written without checking.
+    /// Returns an error, if the pwm is not in closed-loop mode or this sensor doesn't support the subfunction.
+    fn set_target_checked(&self, target: AngularVelocity) -> Result<()> {
+        let enable_path = self
+            .hwmon_path()
+            .join(format!("pwm{}_enable", self.index()));
+
+        match std::fs::read_to_string(&enable_path) {
+            Ok(raw) => match PwmEnable::from_raw(raw.trim())? {
+                PwmEnable::BiosControl => (),
+                pwm_enable => return Err(Error::closed_loop_required(pwm_enable)),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => (),
+            Err(e) => return Err(Error::read(e, enable_path)),
+        }
+
+        self.write_target(target)
+    }
+
     /// Converts div and writes it to this fan's divisor subfunction.
-    /// Returns an error, if this sensor doesn't support the subfunction.
+    /// Only the divisors 1 to 8 accepted by most chips are written, see `FanDivisor::try_new`.
+    /// Some chips like nct6775 and it87 also accept divisors from 16 to 128, which this refuses.
+    /// Use `write_raw` with `SensorSubFunctionType::Div` to write those.
+    /// Returns an error, if div is refused or this sensor doesn't support the subfunction.
     fn write_div(&self, div: FanDivisor) -> Result<()> {
+        FanDivisor::try_new(div.as_u8())
+            .map_err(|e| Error::unit(e, self.subfunction_path(SensorSubFunctionType::Div)))?;
+
         self.write_raw(SensorSubFunctionType::Div, &div.to_raw())
     }
 
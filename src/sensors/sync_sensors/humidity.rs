@@ -10,10 +10,10 @@ use std::path::{Path, PathBuf};
 /// Helper trait that sums up all functionality of a read-only humidity sensor.
 pub trait HumiditySensor: Sensor<Value = Ratio> + std::fmt::Debug {
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw()? != 0)
     }
 
     /// Reads the input subfunction of this sensor.
@@ -29,6 +29,14 @@ pub trait HumiditySensor: Sensor<Value = Ratio> + std::fmt::Debug {
 pub(crate) struct HumiditySensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl HumiditySensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
 impl Sensor for HumiditySensorStruct {
@@ -45,6 +53,23 @@ impl Sensor for HumiditySensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 impl Parseable for HumiditySensorStruct {
@@ -54,6 +79,8 @@ impl Parseable for HumiditySensorStruct {
         let humidity = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(humidity, SensorSubFunctionType::Input)
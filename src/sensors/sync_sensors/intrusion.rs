@@ -11,8 +11,7 @@ pub trait IntrusionSensor: Sensor<Value = bool> + std::fmt::Debug {
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -28,6 +27,14 @@ pub trait IntrusionSensor: Sensor<Value = bool> + std::fmt::Debug {
 pub(crate) struct IntrusionSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl IntrusionSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
 impl Sensor for IntrusionSensorStruct {
@@ -44,6 +51,23 @@ impl Sensor for IntrusionSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 impl Parseable for IntrusionSensorStruct {
@@ -53,6 +77,8 @@ impl Parseable for IntrusionSensorStruct {
         let intrusion = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(intrusion, SensorSubFunctionType::Alarm)
@@ -66,7 +92,11 @@ impl Parseable for IntrusionSensorStruct {
 impl IntrusionSensor for IntrusionSensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl WriteableSensor for IntrusionSensorStruct {}
+impl WriteableSensor for IntrusionSensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Alarm
+    }
+}
 
 #[cfg(feature = "writeable")]
 /// Helper trait that sums up all functionality of a read-write intrusion sensor.
@@ -1,5 +1,7 @@
 //! Module containing the sync sensors and their functionality.
 
+pub mod any;
+pub mod csv;
 pub mod curr;
 pub mod energy;
 pub mod fan;
@@ -7,7 +9,10 @@ pub mod humidity;
 pub mod intrusion;
 pub mod power;
 pub mod pwm;
+pub mod scaled;
+pub mod smoothed;
 pub mod temp;
+pub mod transform;
 pub mod voltage;
 
 #[cfg(feature = "virtual_sensors")]
@@ -18,15 +23,15 @@ use super::error::{Error, Result};
 use crate::hwmon::sync_hwmon::Hwmon;
 use crate::parsing::{Error as ParsingError, Result as ParsingResult};
 use crate::sensors::SensorSubFunctionType;
-use crate::units::Raw;
+use crate::units::{Error as UnitError, Raw};
 
 #[cfg(feature = "writeable")]
 use std::{collections::HashMap, fs::write};
 
 use std::{
-    fs::read_to_string,
+    fs::read,
     path::{Path, PathBuf},
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 /// Base trait that all sensors must implement.
@@ -49,6 +54,18 @@ pub trait Sensor {
     /// Returns this sensor's hwmon's path.
     fn hwmon_path(&self) -> &Path;
 
+    /// Returns whether this sensor's alarm subfunctions are active low, meaning `0` signals an alarm.
+    /// Sensors wrapping another sensor must forward this.
+    fn alarm_active_low(&self) -> bool {
+        false
+    }
+
+    /// Sets whether this sensor's alarm subfunctions are active low, meaning `0` signals an alarm.
+    /// This is only needed for the few drivers that invert the polarity of some of their alarms.
+    /// All alarm readers of this sensor honor the polarity. Use `Hwmon::set_alarm_polarity` to set
+    /// it for all sensors of a hwmon at once.
+    fn set_alarm_polarity(&mut self, active_low: bool);
+
     /// Returns a list of all readable subfunction types supported by this sensor.
     fn supported_read_sub_functions(&self) -> Vec<SensorSubFunctionType> {
         SensorSubFunctionType::read_list()
@@ -68,10 +85,19 @@ pub trait Sensor {
     /// automatically convert the read value to the right type.
     /// Returns an error, if this sensor doesn't support the subtype.
     fn read_raw(&self, sub_type: SensorSubFunctionType) -> Result<String> {
+        let bytes = self.read_raw_bytes(sub_type)?;
+
+        Ok(String::from_utf8_lossy(&bytes).trim().to_string())
+    }
+
+    /// Reads the given subfunction of this sensor and returns its unmodified content as bytes.
+    /// Unlike read_raw, this doesn't fail if a driver writes content that is not valid UTF-8.
+    /// Returns an error, if this sensor doesn't support the subtype.
+    fn read_raw_bytes(&self, sub_type: SensorSubFunctionType) -> Result<Vec<u8>> {
         let path = self.subfunction_path(sub_type);
 
-        match read_to_string(&path) {
-            Ok(s) => Ok(s.trim().to_string()),
+        match read(&path) {
+            Ok(bytes) => Ok(bytes),
             Err(e) => match e.kind() {
                 std::io::ErrorKind::NotFound => Err(Error::subtype_not_supported(sub_type)),
                 std::io::ErrorKind::PermissionDenied => Err(Error::insufficient_rights(path)),
@@ -80,6 +106,96 @@ pub trait Sensor {
         }
     }
 
+    /// Reads this sensor's enable subfunction and returns the numeric enable state.
+    /// Most chips only use 0 and 1, but some use further states like 2 for
+    /// "enabled with higher sampling rate", which `read_enable` can't distinguish.
+    /// Returns an error, if the sensor doesn't support the feature.
+    fn read_enable_raw(&self) -> Result<u8> {
+        let raw = self.read_raw(SensorSubFunctionType::Enable)?;
+        Ok(raw.parse::<u8>().map_err(UnitError::parsing)?)
+    }
+
+    /// Reads the alarm subfunction with the given type and returns whether an alarm condition exists,
+    /// honoring this sensor's alarm polarity.
+    /// Returns an error, if this sensor doesn't support the subtype.
+    fn read_alarm_subfunction(&self, sub_type: SensorSubFunctionType) -> Result<bool> {
+        let raw = self.read_raw(sub_type)?;
+        let alarm = bool::from_raw(&raw)?;
+
+        Ok(alarm != self.alarm_active_low())
+    }
+
+    /// Reads this sensor's input subfunction and returns its value.
+    /// Returns `Ok(None)`, if this sensor has no input subfunction.
+    /// Returns an error, if the input exists but can't be read or converted.
+    fn try_read_input(&self) -> Result<Option<Self::Value>> {
+        match self.read_raw(SensorSubFunctionType::Input) {
+            Ok(raw) => Ok(Some(Self::Value::from_raw(&raw)?)),
+            Err(Error::SubtypeNotSupported { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reads this sensor's input subfunction and returns the raw string alongside the converted value.
+    /// This is meant for debugging unit conversions and filing bug reports.
+    /// Returns an error, if this sensor doesn't support the subtype.
+    fn read_input_debug(&self) -> Result<(String, Self::Value)> {
+        let raw = self.read_raw(SensorSubFunctionType::Input)?;
+        let value = Self::Value::from_raw(&raw)?;
+
+        Ok((raw, value))
+    }
+
+    /// Tries to read the given subfunctions in order and returns the first one that can be read
+    /// and converted, together with its value.
+    /// This allows expressing preferences like "crit, else max, else input" concisely.
+    /// Returns `SubtypeNotSupported` for the first given subfunction, if none of them can be read.
+    fn read_first_available(
+        &self,
+        subs: &[SensorSubFunctionType],
+    ) -> Result<(SensorSubFunctionType, Self::Value)> {
+        for &sub in subs {
+            if let Ok(raw) = self.read_raw(sub) {
+                if let Ok(value) = Self::Value::from_raw(&raw) {
+                    return Ok((sub, value));
+                }
+            }
+        }
+
+        Err(Error::subtype_not_supported(
+            subs.first()
+                .copied()
+                .unwrap_or(SensorSubFunctionType::Input),
+        ))
+    }
+
+    /// Returns the last modification time of this sensor's subfunction with the given type.
+    /// Many drivers update a file's modification time whenever its value changes, which can be used
+    /// to detect a sensor that stopped updating. This behavior is driver dependent though, so an
+    /// unchanging modification time does not necessarily mean that the sensor is stale.
+    /// Returns an error, if this sensor doesn't support the subtype.
+    fn subfunction_mtime(&self, sub_type: SensorSubFunctionType) -> Result<SystemTime> {
+        let path = self.subfunction_path(sub_type);
+
+        match path.metadata().and_then(|metadata| metadata.modified()) {
+            Ok(mtime) => Ok(mtime),
+            Err(e) => match e.kind() {
+                std::io::ErrorKind::NotFound => Err(Error::subtype_not_supported(sub_type)),
+                std::io::ErrorKind::PermissionDenied => Err(Error::insufficient_rights(path)),
+                _ => Err(Error::read(e, path)),
+            },
+        }
+    }
+
+    /// Returns a wrapper around this sensor that implements `Display`.
+    /// Formatting the wrapper prints the sensor's name and its current input like `CPU: 42.5°C`.
+    fn display(&self) -> DisplaySensor<'_, Self>
+    where
+        Self: Sized,
+    {
+        DisplaySensor(self)
+    }
+
     /// Returns the path this sensor's subfunction of the given type would have.
     fn subfunction_path(&self, sub_type: SensorSubFunctionType) -> PathBuf {
         self.hwmon_path().join(format!(
@@ -89,6 +205,104 @@ pub trait Sensor {
             sub_type.to_suffix()
         ))
     }
+
+    /// Returns the paths of all subfunction files of this sensor that exist.
+    fn present_files(&self) -> Vec<PathBuf> {
+        SensorSubFunctionType::all_list()
+            .map(|sub_type| self.subfunction_path(sub_type))
+            .filter(|path| path.exists())
+            .collect()
+    }
+
+    /// Returns the path of this sensor's input file, e.g. to `stat` it or hand it to another process.
+    fn input_path(&self) -> PathBuf {
+        self.subfunction_path(SensorSubFunctionType::Input)
+    }
+
+    /// Returns a wrapper around this sensor whose input is passed through `transform` as a raw
+    /// integer before it is converted to the sensor's unit, e.g. to correct a known offset.
+    /// All other subfunctions are read unchanged.
+    fn with_transform(self, transform: fn(i64) -> i64) -> transform::TransformedSensor<Self>
+    where
+        Self: Sized,
+    {
+        transform::TransformedSensor::new(self, transform)
+    }
+}
+
+/// Wrapper around a sensor that reads its input whenever it is formatted.
+/// It prints the sensor's name and input like `CPU: 42.5°C` or `CPU: <err>`, if the input can't be read.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplaySensor<'a, S: Sensor>(&'a S);
+
+impl<S> std::fmt::Display for DisplaySensor<'_, S>
+where
+    S: Sensor,
+    S::Value: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0.read_input_debug() {
+            Ok((_, value)) => write!(f, "{}: {}", self.0.name(), value),
+            Err(_) => write!(f, "{}: <err>", self.0.name()),
+        }
+    }
+}
+
+/// Object safe trait for dynamically dispatched sensors of any kind.
+/// This allows storing different kinds of sensors in one collection like `Vec<Box<dyn DynSensor>>`.
+pub trait DynSensor: std::fmt::Debug + Send + Sync {
+    /// Returns this sensor's base like "temp" or "fan".
+    fn base(&self) -> &'static str;
+
+    /// Returns this sensor's index.
+    fn index(&self) -> u16;
+
+    /// Returns this sensor's hwmon's path.
+    fn hwmon_path(&self) -> &Path;
+
+    /// If this sensor has a label, its contents are returned.
+    /// Otherwise a plain sensor descriptor is returned.
+    fn name(&self) -> String;
+
+    /// Reads this sensor's input subfunction and returns its value as a raw string.
+    /// Returns an error, if this sensor doesn't support the subtype.
+    fn read_input_raw(&self) -> Result<String>;
+
+    /// Reads the given subfunction of this sensor and returns its value as a raw string.
+    /// Returns an error, if this sensor doesn't support the subtype.
+    fn read_subfunction_raw(&self, sub_type: SensorSubFunctionType) -> Result<String>;
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DynSensorStruct<S>(pub(crate) S);
+
+impl<S> DynSensor for DynSensorStruct<S>
+where
+    S: Sensor + std::fmt::Debug + Send + Sync,
+{
+    fn base(&self) -> &'static str {
+        self.0.base()
+    }
+
+    fn index(&self) -> u16 {
+        self.0.index()
+    }
+
+    fn hwmon_path(&self) -> &Path {
+        self.0.hwmon_path()
+    }
+
+    fn name(&self) -> String {
+        self.0.name()
+    }
+
+    fn read_input_raw(&self) -> Result<String> {
+        self.0.read_raw(SensorSubFunctionType::Input)
+    }
+
+    fn read_subfunction_raw(&self, sub_type: SensorSubFunctionType) -> Result<String> {
+        self.0.read_raw(sub_type)
+    }
 }
 
 /// Base trait that all writeable sensors must implement.
@@ -121,6 +335,28 @@ pub trait WriteableSensor: Sensor {
         })
     }
 
+    /// Returns the subfunction that write controls for this sensor usually write to, like the max
+    /// limit for temperatures or the duty cycle for pwms.
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Max
+    }
+
+    /// Returns whether this sensor's primary writeable subfunction can be opened for writing with
+    /// the current privileges, without actually writing to it, see `primary_write_subfunction`.
+    /// This can be used to decide whether write controls should be offered at all.
+    fn can_write(&self) -> bool {
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(self.subfunction_path(self.primary_write_subfunction()))
+            .is_ok()
+    }
+
+    /// Returns whether this sensor supports resetting its history, without writing to it.
+    fn supports_reset_history(&self) -> bool {
+        self.subfunction_path(SensorSubFunctionType::ResetHistory)
+            .exists()
+    }
+
     /// Resets this sensor's history.
     /// Returns an error if this functionality is not supported by the sensor.
     fn reset_history(&self) -> Result<()> {
@@ -161,7 +397,7 @@ pub trait WriteableSensor: Sensor {
     /// All subfunction types contained in the given state that are not supported by this sensor will be ignored.
     fn write_state_lossy(&self, state: &SensorState) -> Result<()> {
         for (&sub_type, raw_value) in &state.states {
-            if let Err(e) = self.write_raw(sub_type, &raw_value) {
+            if let Err(e) = self.write_raw(sub_type, raw_value) {
                 match e {
                     Error::SubtypeNotSupported { .. } => continue,
                     _ => return Err(e),
@@ -198,11 +434,17 @@ fn inspect_sensor<S: Sensor>(
     sensor: S,
     primary_subfunction: SensorSubFunctionType,
 ) -> ParsingResult<S> {
-    if let Err(e) = sensor.subfunction_path(primary_subfunction).metadata() {
-        return Err(ParsingError::sensor(
-            e,
-            sensor.subfunction_path(primary_subfunction),
-        ));
+    let path = sensor.subfunction_path(primary_subfunction);
+
+    if let Err(e) = path.metadata() {
+        return Err(ParsingError::sensor(e, path));
+    }
+
+    // Missing read permissions only show up when actually opening the file.
+    if let Err(e) = std::fs::File::open(&path) {
+        if e.kind() == std::io::ErrorKind::PermissionDenied {
+            return Err(ParsingError::sensor(e, path));
+        }
     }
 
     Ok(sensor)
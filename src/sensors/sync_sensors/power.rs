@@ -3,7 +3,8 @@
 use super::*;
 use crate::hwmon::sync_hwmon::Hwmon;
 use crate::parsing::{Parseable, Result as ParsingResult};
-use crate::units::{Power, Ratio, Raw};
+use crate::sensors::optional;
+use crate::units::{Power, Ratio, Raw, RawInteger};
 
 /// Helper trait that sums up all functionality of a read-only power sensor.
 pub trait PowerSensor: Sensor<Value = Power> + std::fmt::Debug {
@@ -42,6 +43,19 @@ pub trait PowerSensor: Sensor<Value = Power> + std::fmt::Debug {
         Power::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the cap and cap_hyst subfunctions of this power sensor and returns the power level
+    /// `cap - cap_hyst` below which a cap alarm is cleared.
+    /// cap_hyst is interpreted as the width of the band below cap and the result is clamped at 0.
+    /// Returns an error, if this sensor doesn't support one of the subfunctions.
+    fn cap_clear_level(&self) -> Result<Power> {
+        let cap = self.read_cap()?;
+        let cap_hyst = self.read_cap_hyst()?;
+
+        let clear_level = (cap.to_raw_integer() - cap_hyst.to_raw_integer()).max(0);
+
+        Ok(Power::try_from_raw_integer(clear_level)?)
+    }
+
     /// Reads the average_interval subfunction of this power sensor.
     /// Returns an error, if this sensor doesn't support the subfunction.
     fn read_average_interval(&self) -> Result<Duration> {
@@ -63,6 +77,14 @@ pub trait PowerSensor: Sensor<Value = Power> + std::fmt::Debug {
         Duration::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the max_interval subfunction of this power sensor.
+    /// This is the time window the max subfunction applies to.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    fn read_max_interval(&self) -> Result<Duration> {
+        let raw = self.read_raw(SensorSubFunctionType::MaxInterval)?;
+        Duration::from_raw(&raw).map_err(Error::from)
+    }
+
     /// Reads the average_highest subfunction of this power sensor.
     /// Returns an error, if this sensor doesn't support the subfunction.
     fn read_average_highest(&self) -> Result<Power> {
@@ -92,10 +114,10 @@ pub trait PowerSensor: Sensor<Value = Power> + std::fmt::Debug {
     }
 
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw()? != 0)
     }
 
     /// Reads the input subfunction of this sensor.
@@ -140,25 +162,53 @@ pub trait PowerSensor: Sensor<Value = Power> + std::fmt::Debug {
         Self::Value::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the input_highest subfunction of this power sensor, which some chips use to track
+    /// the highest input since the history was last reset.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    fn read_input_highest(&self) -> Result<Power> {
+        let raw = self.read_raw(SensorSubFunctionType::InputHighest)?;
+        Power::from_raw(&raw).map_err(Error::from)
+    }
+
+    /// Reads the input_lowest subfunction of this power sensor, which some chips use to track
+    /// the lowest input since the history was last reset.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    fn read_input_lowest(&self) -> Result<Power> {
+        let raw = self.read_raw(SensorSubFunctionType::InputLowest)?;
+        Power::from_raw(&raw).map_err(Error::from)
+    }
+
+    /// Returns the power limit this chip actually enforces, which is the lower of the cap and
+    /// crit subfunctions.
+    /// Returns `Ok(None)`, if this sensor supports neither subfunction.
+    /// Returns an error, if a supported limit can't be read.
+    fn effective_limit(&self) -> Result<Option<Power>> {
+        let cap = optional(self.read_cap())?;
+        let crit = optional(self.read_crit())?;
+
+        Ok(match (cap, crit) {
+            (Some(cap), Some(crit)) if crit < cap => Some(crit),
+            (Some(cap), _) => Some(cap),
+            (None, crit) => crit,
+        })
+    }
+
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
     }
 
     /// Reads whether or not an alarm condition exists for the crit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_crit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::CritAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::CritAlarm)
     }
 
     /// Reads whether or not an alarm condition exists for the cap subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_cap_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::CapAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::CapAlarm)
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -174,6 +224,14 @@ pub trait PowerSensor: Sensor<Value = Power> + std::fmt::Debug {
 pub(crate) struct PowerSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl PowerSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
 impl Sensor for PowerSensorStruct {
@@ -190,6 +248,23 @@ impl Sensor for PowerSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 impl Parseable for PowerSensorStruct {
@@ -199,6 +274,8 @@ impl Parseable for PowerSensorStruct {
         let power = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(power, SensorSubFunctionType::Input)
@@ -212,7 +289,11 @@ impl Parseable for PowerSensorStruct {
 impl PowerSensor for PowerSensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl WriteableSensor for PowerSensorStruct {}
+impl WriteableSensor for PowerSensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Cap
+    }
+}
 
 #[cfg(feature = "writeable")]
 /// Helper trait that sums up all functionality of a read-write power sensor.
@@ -235,6 +316,33 @@ pub trait WriteablePowerSensor: PowerSensor + WriteableSensor {
         self.write_raw(SensorSubFunctionType::AverageInterval, &interval.to_raw())
     }
 
+    /// Converts interval and writes it to the max_interval subfunction of this power sensor.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    fn write_max_interval(&self, interval: Duration) -> Result<()> {
+        self.write_raw(SensorSubFunctionType::MaxInterval, &interval.to_raw())
+    }
+
+    /// Clamps interval to the range given by the average_interval_min and average_interval_max
+    /// subfunctions and writes it to the average_interval subfunction of this power sensor.
+    /// Bounds that this sensor doesn't expose are not applied.
+    /// Returns the interval that was actually written.
+    /// Returns an error, if this sensor doesn't support the average_interval subfunction.
+    fn set_average_interval_clamped(&self, interval: Duration) -> Result<Duration> {
+        let mut interval = interval;
+
+        if let Ok(min) = self.read_average_interval_min() {
+            interval = interval.max(min);
+        }
+
+        if let Ok(max) = self.read_average_interval_max() {
+            interval = interval.min(max);
+        }
+
+        self.write_average_interval(interval)?;
+
+        Ok(interval)
+    }
+
     /// Sets this sensor's enabled state.
     /// Returns an error, if the sensor doesn't support the feature.
     fn write_enable(&self, enable: bool) -> Result<()> {
@@ -2,9 +2,14 @@
 
 use super::*;
 use crate::parsing::{Parseable, Result as ParsingResult};
+#[cfg(feature = "writeable")]
+use crate::sensors::ClampInfo;
+use crate::sensors::{optional, PwmState};
 use crate::units::{Frequency, Pwm, PwmEnable, PwmMode, Raw};
 
 use std::path::Path;
+#[cfg(feature = "writeable")]
+use std::{thread::JoinHandle, time::Duration};
 
 /// Helper trait that sums up all functionality of a read-only pwm sensor.
 pub trait PwmSensor: Sensor<Value = Pwm> + std::fmt::Debug {
@@ -22,6 +27,18 @@ pub trait PwmSensor: Sensor<Value = Pwm> + std::fmt::Debug {
         PwmEnable::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Returns whether this pwm is under manual control, i.e. its enable subfunction is `ManualControl`.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    fn is_under_manual_control(&self) -> Result<bool> {
+        Ok(self.read_enable()? == PwmEnable::ManualControl)
+    }
+
+    /// Returns whether this pwm runs at full speed, i.e. its enable subfunction is `FullSpeed`.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    fn is_full_speed(&self) -> Result<bool> {
+        Ok(self.read_enable()? == PwmEnable::FullSpeed)
+    }
+
     /// Reads the mode subfunction of this pwm sensor.
     /// Returns an error, if this sensor doesn't support the subfunction.
     fn read_mode(&self) -> Result<PwmMode> {
@@ -35,6 +52,49 @@ pub trait PwmSensor: Sensor<Value = Pwm> + std::fmt::Debug {
         let raw = self.read_raw(SensorSubFunctionType::Freq)?;
         Frequency::from_raw(&raw).map_err(Error::from)
     }
+
+    /// Reads the duty cycle of this pwm as a ratio between 0 and 1 alongside its frequency.
+    /// The frequency is `None`, if this sensor has no freq subfunction.
+    /// Returns an error, if this sensor's pwm subfunction can't be read.
+    fn duty_and_frequency(&self) -> Result<(f64, Option<Frequency>)> {
+        let duty = f64::from(self.read_pwm()?.as_u8()) / f64::from(u8::MAX);
+        let frequency = optional(self.read_frequency())?;
+
+        Ok((duty, frequency))
+    }
+
+    /// Returns the number of points of this pwm's auto fan curve (pwmN_auto_pointM_temp files).
+    /// Returns 0, if this pwm has no auto points.
+    fn auto_point_count(&self) -> usize {
+        let mut count = 0;
+
+        loop {
+            let path = self.hwmon_path().join(format!(
+                "{}{}_auto_point{}_temp",
+                self.base(),
+                self.index(),
+                count + 1
+            ));
+
+            if !path.exists() {
+                return count;
+            }
+
+            count += 1;
+        }
+    }
+
+    /// Reads the pwm, enable, mode and freq subfunctions of this pwm sensor at once.
+    /// Subfunctions this sensor doesn't support are returned as `None`.
+    /// Returns an error, if a supported subfunction can't be read.
+    fn read_state(&self) -> Result<PwmState> {
+        Ok(PwmState {
+            pwm: optional(self.read_pwm())?,
+            enable: optional(self.read_enable())?,
+            mode: optional(self.read_mode())?,
+            frequency: optional(self.read_frequency())?,
+        })
+    }
 }
 
 /// Struct that represents a read only pwm sensor.
@@ -42,6 +102,14 @@ pub trait PwmSensor: Sensor<Value = Pwm> + std::fmt::Debug {
 pub(crate) struct PwmSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl PwmSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
 impl Sensor for PwmSensorStruct {
@@ -58,6 +126,23 @@ impl Sensor for PwmSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 impl Parseable for PwmSensorStruct {
@@ -67,6 +152,8 @@ impl Parseable for PwmSensorStruct {
         let pwm = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(pwm, SensorSubFunctionType::Pwm)
@@ -80,7 +167,11 @@ impl Parseable for PwmSensorStruct {
 impl PwmSensor for PwmSensorStruct {}
 
 #[cfg(feature = "writeable")]
-impl WriteableSensor for PwmSensorStruct {}
+impl WriteableSensor for PwmSensorStruct {
+    fn primary_write_subfunction(&self) -> SensorSubFunctionType {
+        SensorSubFunctionType::Pwm
+    }
+}
 
 #[cfg(feature = "writeable")]
 /// Helper trait that sums up all functionality of a read-write pwm sensor.
@@ -91,6 +182,77 @@ pub trait WriteablePwmSensor: PwmSensor + WriteableSensor {
         self.write_raw(SensorSubFunctionType::Pwm, &pwm.to_raw())
     }
 
+    /// Writes pwm to this pwm's pwm subfunction and reads it back to find out whether the chip
+    /// clamped or rounded the value.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    fn write_pwm_clamped_info(&self, pwm: Pwm) -> Result<ClampInfo> {
+        self.write_pwm(pwm)?;
+        let stored = self.read_pwm()?;
+
+        Ok(ClampInfo::new(pwm, stored))
+    }
+
+    /// Switches this pwm to manual control and writes pwm to its pwm subfunction for the
+    /// duration of `revert_after`. Afterwards a background thread restores the pwm and enable
+    /// values this sensor had before, even if the returned handle is dropped.
+    /// The raw enable value is restored, so vendor specific automatic modes are kept.
+    /// Returns an error, if the prior state can't be read or the override can't be written.
+    /// If the override can't be written, the prior enable value is restored before returning.
+    /// The revert always attempts to restore both values, the first error is returned by the
+    /// handle.
+    fn write_pwm_temporary(
+        &self,
+        pwm: Pwm,
+        revert_after: Duration,
+    ) -> Result<JoinHandle<Result<()>>>
+    where
+        Self: Clone + Send + Sized + 'static,
+    {
+        let prior_pwm = self.read_pwm()?;
+        // The raw value is kept, because `PwmEnable` folds vendor specific automatic modes above 2
+        // into `BiosControl`.
+        let prior_enable = optional(self.read_enable_raw())?;
+
+        let switched = prior_enable
+            .is_some_and(|enable| enable.to_string() != PwmEnable::ManualControl.to_raw());
+
+        if switched {
+            self.write_enable(PwmEnable::ManualControl)?;
+        }
+        if let Err(e) = self.write_pwm(pwm) {
+            // The override failed, so hand control back instead of leaving the pwm in manual mode.
+            if let Some(enable) = prior_enable.filter(|_| switched) {
+                let _ = self.write_raw(SensorSubFunctionType::Enable, &enable.to_string());
+            }
+
+            return Err(e);
+        }
+
+        let sensor = self.clone();
+
+        Ok(std::thread::spawn(move || {
+            std::thread::sleep(revert_after);
+
+            let pwm_result = sensor.write_pwm(prior_pwm);
+            let enable_result = prior_enable.map_or(Ok(()), |enable| {
+                sensor.write_raw(SensorSubFunctionType::Enable, &enable.to_string())
+            });
+
+            pwm_result.and(enable_result)
+        }))
+    }
+
+    /// Writes pwm to this pwm's pwm subfunction but never below floor, so fans can't be set to a
+    /// speed at which they stall. A pwm of exactly 0 is written as is to allow stopping the fan.
+    /// Returns an error, if this sensor doesn't support the subfunction.
+    fn write_pwm_floored(&self, pwm: Pwm, floor: Pwm) -> Result<()> {
+        if pwm.as_u8() == 0 {
+            self.write_pwm(pwm)
+        } else {
+            self.write_pwm(pwm.max(floor))
+        }
+    }
+
     /// Converts enable and writes it to this pwm's enable subfunction.
     /// Returns an error, if this sensor doesn't support the subfunction.
     fn write_enable(&self, enable: PwmEnable) -> Result<()> {
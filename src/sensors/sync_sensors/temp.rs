@@ -1,9 +1,18 @@
 //! Module containing the temp sensors and their related functionality.
 
+use super::scaled::ScaledTempSensor;
+use super::smoothed::SmoothedTempSensor;
 use super::*;
 use crate::hwmon::sync_hwmon::Hwmon;
 use crate::parsing::{Parseable, Result as ParsingResult};
-use crate::units::{Raw, TempType, Temperature};
+use crate::sensors::thermal_zone::{thermal_zone_path, trip_point_paths, CRITICAL_TRIP_TYPE};
+use crate::sensors::{optional, BurstStats, LimitStatus, TempReadings};
+use crate::units::{Error as UnitError, Raw, RawInteger, TempType, Temperature};
+
+#[cfg(feature = "writeable")]
+use crate::sensors::check_ordering;
+#[cfg(feature = "writeable")]
+use crate::units::parse_lenient_celsius;
 
 use std::path::{Path, PathBuf};
 
@@ -73,10 +82,10 @@ pub trait TempSensor: Sensor<Value = Temperature> + std::fmt::Debug {
     }
 
     /// Reads whether or not this sensor is enabled.
+    /// Any non-zero enable state counts as enabled, see `read_enable_raw`.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_enable(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Enable)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        Ok(self.read_enable_raw()? != 0)
     }
 
     /// Reads the input subfunction of this temp sensor.
@@ -90,6 +99,106 @@ pub trait TempSensor: Sensor<Value = Temperature> + std::fmt::Debug {
         Temperature::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the input subfunction of this temp sensor for chips that store it as a 16 bit two's
+    /// complement value but report it unsigned, so sub-zero temperatures show up as values above
+    /// 32767 (e.g. "65036" for -0.5°C).
+    /// Only use this for drivers known to have this bug: it limits the range to ±32.767°C and
+    /// misreads correct inputs above that.
+    /// Returns an error, if this sensor doesn't support the subtype or the input doesn't fit 16 bits.
+    fn read_input_signed16(&self) -> Result<Temperature> {
+        let raw = self.read_raw(SensorSubFunctionType::Input)?;
+        let unsigned = raw.parse::<u16>().map_err(UnitError::parsing)?;
+
+        Ok(Temperature::from_raw(&(unsigned as i16).to_string())?)
+    }
+
+    /// Reads the input of this sensor and compares it to the min, max and crit subfunctions.
+    /// Limits this sensor doesn't support are not checked.
+    /// This is the single call a gauge needs to pick its color.
+    /// Returns an error, if the input or one of the supported limits can't be read.
+    fn read_input_status(&self) -> Result<(Temperature, LimitStatus)> {
+        let input = self.read_input()?;
+        let min = optional(self.read_min())?;
+        let max = optional(self.read_max())?;
+        let crit = optional(self.read_crit())?;
+
+        Ok((input, LimitStatus::classify(&input, min, max, crit)))
+    }
+
+    /// Returns the most severe threshold of this sensor in the order emergency, crit and max.
+    /// This is the single temperature a UI should present as the one to avoid.
+    /// Returns `Ok(None)`, if this sensor supports none of these subfunctions.
+    /// Returns an error, if a supported threshold can't be read.
+    fn danger_threshold(&self) -> Result<Option<Temperature>> {
+        if let Some(emergency) = optional(self.read_emergency())? {
+            return Ok(Some(emergency));
+        }
+        if let Some(crit) = optional(self.read_crit())? {
+            return Ok(Some(crit));
+        }
+
+        optional(self.read_max())
+    }
+
+    /// Polls this sensor's input every `interval` until `window` consecutive readings lie within
+    /// `tolerance` of each other and returns their average.
+    /// This is useful for waiting until a sensor has warmed up.
+    /// Returns `Unstable`, if the readings didn't stabilize within `max_attempts` readings.
+    /// Returns an error, if the input can't be read.
+    fn read_stable(
+        &self,
+        tolerance: Temperature,
+        window: usize,
+        interval: std::time::Duration,
+        max_attempts: usize,
+    ) -> Result<Temperature> {
+        let window = window.max(1);
+        let mut readings = std::collections::VecDeque::with_capacity(window);
+
+        for attempt in 0..max_attempts {
+            if attempt > 0 {
+                std::thread::sleep(interval);
+            }
+
+            readings.push_back(self.read_input()?.to_raw_integer());
+
+            if readings.len() > window {
+                readings.pop_front();
+            }
+
+            if readings.len() == window {
+                let min = readings.iter().min().copied().unwrap_or_default();
+                let max = readings.iter().max().copied().unwrap_or_default();
+
+                if max - min <= tolerance.to_raw_integer() {
+                    let sum: i64 = readings.iter().sum();
+                    return Ok(Temperature::try_from_raw_integer(sum / window as i64)?);
+                }
+            }
+        }
+
+        Err(Error::unstable(max_attempts))
+    }
+
+    /// Reads this sensor's input `count` times with `interval` in between and returns statistics
+    /// over the readings. This is useful to find out how noisy a sensor is.
+    /// Returns an error, if the input can't be read.
+    fn read_burst_stats(&self, count: usize, interval: std::time::Duration) -> Result<BurstStats> {
+        let count = count.max(1);
+        let mut readings = Vec::with_capacity(count);
+
+        for i in 0..count {
+            if i > 0 {
+                std::thread::sleep(interval);
+            }
+
+            readings.push(self.read_input()?);
+        }
+
+        BurstStats::from_readings(&readings)
+            .ok_or_else(|| Error::subtype_not_supported(SensorSubFunctionType::Input))
+    }
+
     /// Reads this sensor's min value.
     /// Returns an error, if this sensor doesn't support the feature.
     fn read_min(&self) -> Result<Self::Value> {
@@ -111,6 +220,79 @@ pub trait TempSensor: Sensor<Value = Temperature> + std::fmt::Debug {
         Self::Value::from_raw(&raw).map_err(Error::from)
     }
 
+    /// Reads the min and min_hyst subfunctions of this temp sensor and returns the absolute
+    /// temperature `min + min_hyst` above which a min alarm is cleared.
+    /// Unlike [`read_min_hyst`](Self::read_min_hyst), which returns the raw min_hyst value,
+    /// min_hyst is interpreted as the width of the band above min here.
+    /// Returns an error, if this sensor doesn't support one of the subfunctions.
+    fn min_hyst_absolute(&self) -> Result<Temperature> {
+        let min = self.read_min()?;
+        let min_hyst = self.read_min_hyst()?;
+
+        Ok(Temperature::try_from_raw_integer(
+            min.to_raw_integer() + min_hyst.to_raw_integer(),
+        )?)
+    }
+
+    /// Reads the crit and crit_hyst subfunctions of this temp sensor and returns the critical
+    /// hysteresis band as `(crit - crit_hyst, crit)`.
+    /// crit_hyst is interpreted as the width of the band below crit.
+    /// Returns an error, if this sensor doesn't support one of the subfunctions.
+    fn crit_band(&self) -> Result<(Temperature, Temperature)> {
+        let crit = self.read_crit()?;
+        let crit_hyst = self.read_crit_hyst()?;
+
+        let low =
+            Temperature::try_from_raw_integer(crit.to_raw_integer() - crit_hyst.to_raw_integer())?;
+
+        Ok((low, crit))
+    }
+
+    /// Returns the thermal headroom `crit - input` of this temp sensor or `max - input`, if the
+    /// sensor has no crit subfunction.
+    /// Negative values indicate that the limit is exceeded.
+    /// Returns an error, if this sensor supports neither crit nor max or its input can't be read.
+    fn headroom(&self) -> Result<Temperature> {
+        let limit = match self.read_crit() {
+            Err(Error::SubtypeNotSupported { .. }) => self.read_max()?,
+            limit => limit?,
+        };
+        let input = self.read_input()?;
+
+        Ok(Temperature::try_from_raw_integer(
+            limit.to_raw_integer() - input.to_raw_integer(),
+        )?)
+    }
+
+    /// Reads the critical trip point of the thermal zone this sensor's hwmon belongs to.
+    /// This recovers the crit value on ACPI thermal setups whose hwmon lacks a crit subfunction.
+    /// Returns `Ok(None)`, if the hwmon belongs to no thermal zone or the zone has no critical trip point.
+    /// Returns an error, if the trip points of the zone can't be read.
+    fn read_crit_from_thermal_zone(&self) -> Result<Option<Temperature>> {
+        let Some(zone) = thermal_zone_path(self.hwmon_path()) else {
+            return Ok(None);
+        };
+
+        for index in 0.. {
+            let (type_path, temp_path) = trip_point_paths(&zone, index);
+
+            let trip_type = match std::fs::read_to_string(&type_path) {
+                Ok(trip_type) => trip_type,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => break,
+                Err(e) => return Err(Error::read(e, type_path)),
+            };
+
+            if trip_type.trim() == CRITICAL_TRIP_TYPE {
+                let raw =
+                    std::fs::read_to_string(&temp_path).map_err(|e| Error::read(e, &temp_path))?;
+
+                return Ok(Some(Temperature::from_raw(&raw)?));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Reads whether this sensor is faulty or not.
     /// Returns an error, if this sensor doesn't support the feature.
     fn read_faulty(&self) -> Result<bool> {
@@ -121,43 +303,37 @@ pub trait TempSensor: Sensor<Value = Temperature> + std::fmt::Debug {
     /// Reads whether or not an alarm condition exists for the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::Alarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::Alarm)
     }
 
     /// Reads whether or not an alarm condition exists for the min subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_min_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MinAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MinAlarm)
     }
 
     /// Reads whether or not an alarm condition exists for the max subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_max_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::MaxAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::MaxAlarm)
     }
 
     /// Reads whether or not an alarm condition exists for the crit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_crit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::CritAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::CritAlarm)
     }
 
     /// Reads whether or not an alarm condition exists for the lcrit subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_lcrit_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::LowCritAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::LowCritAlarm)
     }
 
     /// Reads whether or not an alarm condition exists for the emergency subfunction of the sensor.
     /// Returns an error, if the sensor doesn't support the feature.
     fn read_emergency_alarm(&self) -> Result<bool> {
-        let raw = self.read_raw(SensorSubFunctionType::EmergencyAlarm)?;
-        bool::from_raw(&raw).map_err(Error::from)
+        self.read_alarm_subfunction(SensorSubFunctionType::EmergencyAlarm)
     }
 
     /// Reads whether or not an alarm condition for the sensor also triggers beeping.
@@ -166,6 +342,54 @@ pub trait TempSensor: Sensor<Value = Temperature> + std::fmt::Debug {
         let raw = self.read_raw(SensorSubFunctionType::Beep)?;
         bool::from_raw(&raw).map_err(Error::from)
     }
+
+    /// Reads every subfunction of this temp sensor independently and returns all results,
+    /// so that a diagnostic dump can show exactly which subfunctions work and which don't.
+    fn read_all_fields(&self) -> TempReadings {
+        TempReadings {
+            input: self.read_input(),
+            min: self.read_min(),
+            max: self.read_max(),
+            crit: self.read_crit(),
+            lcrit: self.read_lcrit(),
+            emergency: self.read_emergency(),
+            min_hyst: self.read_min_hyst(),
+            max_hyst: self.read_max_hyst(),
+            crit_hyst: self.read_crit_hyst(),
+            offset: self.read_offset(),
+            temp_type: self.read_type(),
+            enable: self.read_enable(),
+            faulty: self.read_faulty(),
+            alarm: self.read_alarm(),
+            min_alarm: self.read_min_alarm(),
+            max_alarm: self.read_max_alarm(),
+            crit_alarm: self.read_crit_alarm(),
+            lcrit_alarm: self.read_lcrit_alarm(),
+            emergency_alarm: self.read_emergency_alarm(),
+        }
+    }
+
+    /// Returns a wrapper around this sensor whose `read_input` returns an exponentially weighted
+    /// moving average of its readings with the smoothing factor `alpha`.
+    /// Higher values of `alpha` follow the input more closely, `1.0` disables smoothing.
+    /// Returns an error, if `alpha` is not in the range (0, 1].
+    fn into_smoothed(self, alpha: f64) -> Result<SmoothedTempSensor<Self>>
+    where
+        Self: Sized,
+    {
+        SmoothedTempSensor::new(self, alpha)
+    }
+
+    /// Returns a wrapper around this sensor for chips that report temperatures in `divisor` raw
+    /// units per degree celsius instead of the millidegrees the hwmon ABI mandates,
+    /// e.g. `1` for chips reporting whole degrees.
+    /// Returns an error, if `divisor` is not positive.
+    fn with_scale(self, divisor: i32) -> Result<ScaledTempSensor<Self>>
+    where
+        Self: Sized,
+    {
+        ScaledTempSensor::new(self, divisor)
+    }
 }
 
 /// Struct that represents a read only temp sensor.
@@ -173,6 +397,14 @@ pub trait TempSensor: Sensor<Value = Temperature> + std::fmt::Debug {
 pub(crate) struct TempSensorStruct {
     hwmon_path: PathBuf,
     index: u16,
+    label: Option<String>,
+    alarm_active_low: bool,
+}
+
+impl TempSensorStruct {
+    pub(crate) fn set_label(&mut self, label: impl Into<String>) {
+        self.label = Some(label.into());
+    }
 }
 
 impl Sensor for TempSensorStruct {
@@ -189,6 +421,23 @@ impl Sensor for TempSensorStruct {
     fn hwmon_path(&self) -> &Path {
         self.hwmon_path.as_path()
     }
+
+    fn alarm_active_low(&self) -> bool {
+        self.alarm_active_low
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.alarm_active_low = active_low;
+    }
+
+    fn name(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None => self
+                .read_raw(SensorSubFunctionType::Label)
+                .unwrap_or_else(|_| format!("{}{}", self.base(), self.index())),
+        }
+    }
 }
 
 impl Parseable for TempSensorStruct {
@@ -198,6 +447,8 @@ impl Parseable for TempSensorStruct {
         let temp = Self {
             hwmon_path: parent.path().to_path_buf(),
             index,
+            label: None,
+            alarm_active_low: false,
         };
 
         inspect_sensor(temp, SensorSubFunctionType::Input)
@@ -285,6 +536,36 @@ pub trait WriteableTempSensor: TempSensor + WriteableSensor {
         self.write_raw(SensorSubFunctionType::Max, &max.to_raw())
     }
 
+    /// Writes the given min, max and crit values to this sensor.
+    /// Before anything is written, it is checked that min <= max <= crit for all given values.
+    /// Returns an error, if the values are not ordered or this sensor doesn't support one of the subfunctions.
+    fn write_limits(
+        &self,
+        min: Option<Temperature>,
+        max: Option<Temperature>,
+        crit: Option<Temperature>,
+    ) -> Result<()> {
+        check_ordering(&[
+            (SensorSubFunctionType::Min, min),
+            (SensorSubFunctionType::Max, max),
+            (SensorSubFunctionType::Crit, crit),
+        ])?;
+
+        if let Some(min) = min {
+            self.write_min(min)?;
+        }
+
+        if let Some(max) = max {
+            self.write_max(max)?;
+        }
+
+        if let Some(crit) = crit {
+            self.write_crit(crit)?;
+        }
+
+        Ok(())
+    }
+
     /// Writes this sensor's crit value.
     /// Returns an error, if the sensor doesn't support the feature.
     fn write_crit(&self, crit: Self::Value) -> Result<()> {
@@ -296,6 +577,32 @@ pub trait WriteableTempSensor: TempSensor + WriteableSensor {
     fn write_beep(&self, beep: bool) -> Result<()> {
         self.write_raw(SensorSubFunctionType::Beep, &beep.to_raw())
     }
+
+    /// Parses a human entered temperature like "85C" or "185F" and writes it to the given
+    /// threshold subfunction of this sensor.
+    /// Values without a unit suffix are interpreted as degrees celsius.
+    /// Returns an error, if sub is not a temperature threshold, the string can't be parsed or
+    /// the sensor doesn't support the subfunction.
+    fn write_threshold_str(&self, sub: SensorSubFunctionType, value: &str) -> Result<()> {
+        match sub {
+            SensorSubFunctionType::Min
+            | SensorSubFunctionType::Max
+            | SensorSubFunctionType::Crit
+            | SensorSubFunctionType::LowCrit
+            | SensorSubFunctionType::Emergency
+            | SensorSubFunctionType::MinHyst
+            | SensorSubFunctionType::MaxHyst
+            | SensorSubFunctionType::CritHyst
+            | SensorSubFunctionType::LowCritHyst
+            | SensorSubFunctionType::EmergencyHyst => {}
+            _ => return Err(Error::subtype_not_supported(sub)),
+        }
+
+        let celsius = parse_lenient_celsius(value)?;
+        let temp = Temperature::from_raw(&format!("{:.0}", celsius * 1000.0))?;
+
+        self.write_raw(sub, &temp.to_raw())
+    }
 }
 
 #[cfg(feature = "writeable")]
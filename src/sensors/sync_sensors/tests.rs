@@ -1,7 +1,7 @@
 use super::*;
 use crate::hwmon::sync_hwmon::Hwmons;
 use crate::parsing::Parseable;
-use crate::sensors::sync_sensors::{fan::*, temp::*};
+use crate::sensors::sync_sensors::{curr::*, fan::*, power::*, pwm::*, temp::*, voltage::*};
 use crate::tests::*;
 
 use temp_dir::TempDir;
@@ -56,3 +56,1681 @@ fn test_label() {
 
     assert_eq!(temp.name(), String::from("test_temp1"));
 }
+
+#[test]
+#[cfg(feature = "writeable")]
+fn test_energy_reset() {
+    use crate::sensors::sync_sensors::energy::*;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_energy(1, 123456);
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let energy = EnergySensorStruct::parse(hwmon, 1).unwrap();
+
+    energy.reset().unwrap();
+
+    assert_eq!(energy.read_raw(SensorSubFunctionType::Input).unwrap(), "0");
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_energy_reset_read_only() {
+    use crate::sensors::sync_sensors::energy::*;
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_energy(1, 123456);
+
+    let input_path = test_dir.path().join("hwmon0/energy1_input");
+    std::fs::set_permissions(&input_path, std::fs::Permissions::from_mode(0o444)).unwrap();
+
+    // Root can open read-only files for writing, so the check is only meaningful for other users.
+    if input_path.metadata().unwrap().uid() == 0 {
+        return;
+    }
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let energy = EnergySensorStruct::parse(hwmon, 1).unwrap();
+
+    assert!(matches!(
+        energy.reset(),
+        Err(Error::SubtypeNotSupported {
+            sub_type: SensorSubFunctionType::Input
+        })
+    ));
+    assert_eq!(
+        energy.read_raw(SensorSubFunctionType::Input).unwrap(),
+        "123456"
+    );
+}
+
+#[test]
+fn test_subfunction_mtime() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+    let input_path = temp.subfunction_path(SensorSubFunctionType::Input);
+
+    std::fs::File::options()
+        .write(true)
+        .open(&input_path)
+        .unwrap()
+        .set_modified(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap();
+
+    let old_mtime = temp
+        .subfunction_mtime(SensorSubFunctionType::Input)
+        .unwrap();
+
+    std::fs::write(&input_path, "45000").unwrap();
+
+    let new_mtime = temp
+        .subfunction_mtime(SensorSubFunctionType::Input)
+        .unwrap();
+
+    assert_eq!(old_mtime, std::time::SystemTime::UNIX_EPOCH);
+    assert!(new_mtime > old_mtime);
+    assert!(temp.subfunction_mtime(SensorSubFunctionType::Max).is_err());
+}
+
+#[test]
+fn test_read_input_debug() {
+    use crate::units::Temperature;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 42500, "temp1");
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    let (raw, value) = temp.read_input_debug().unwrap();
+
+    assert_eq!(raw, "42500");
+    assert_eq!(value, Temperature::from_raw("42500").unwrap());
+}
+
+#[test]
+fn test_crit_band() {
+    use crate::units::Temperature;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_crit"), "95000").unwrap();
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert!(temp.crit_band().is_err());
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_crit_hyst"), "3000").unwrap();
+
+    assert_eq!(
+        (
+            Temperature::from_raw("92000").unwrap(),
+            Temperature::from_raw("95000").unwrap()
+        ),
+        temp.crit_band().unwrap()
+    );
+}
+
+#[cfg(not(feature = "uom_units"))]
+#[test]
+fn test_display() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 42500, "CPU Tctl")
+        .add_fan(1, 1200);
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+    let fan = FanSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!("CPU Tctl: 42.5°C", temp.display().to_string());
+    assert_eq!("fan1: 1200rpm", fan.display().to_string());
+
+    std::fs::remove_file(test_dir.path().join("hwmon0/temp1_input")).unwrap();
+
+    assert_eq!("CPU Tctl: <err>", temp.display().to_string());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_write_limits() {
+    use crate::units::Temperature;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    let min = Temperature::from_raw("10000").unwrap();
+    let max = Temperature::from_raw("80000").unwrap();
+    let crit = Temperature::from_raw("95000").unwrap();
+
+    temp.write_limits(Some(min), Some(max), Some(crit)).unwrap();
+
+    assert_eq!(min, temp.read_min().unwrap());
+    assert_eq!(max, temp.read_max().unwrap());
+    assert_eq!(crit, temp.read_crit().unwrap());
+
+    assert!(matches!(
+        temp.write_limits(Some(crit), None, Some(max)),
+        Err(Error::InvalidOrdering {
+            lower: SensorSubFunctionType::Min,
+            upper: SensorSubFunctionType::Crit
+        })
+    ));
+    assert_eq!(min, temp.read_min().unwrap());
+}
+
+/// Temp sensor that returns a scripted sequence of inputs, so polling tests don't depend on timing.
+#[derive(Debug)]
+struct ScriptedTempSensor {
+    inputs: std::cell::RefCell<std::collections::VecDeque<&'static str>>,
+}
+
+impl ScriptedTempSensor {
+    fn new(inputs: &[&'static str]) -> Self {
+        Self {
+            inputs: std::cell::RefCell::new(inputs.iter().copied().collect()),
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.inputs.borrow().len()
+    }
+}
+
+impl Sensor for ScriptedTempSensor {
+    type Value = crate::units::Temperature;
+
+    fn base(&self) -> &'static str {
+        "temp"
+    }
+
+    fn index(&self) -> u16 {
+        1
+    }
+
+    fn hwmon_path(&self) -> &std::path::Path {
+        std::path::Path::new("")
+    }
+
+    fn set_alarm_polarity(&mut self, _active_low: bool) {}
+
+    fn read_raw(&self, sub_type: SensorSubFunctionType) -> Result<String> {
+        match sub_type {
+            SensorSubFunctionType::Input => Ok(self
+                .inputs
+                .borrow_mut()
+                .pop_front()
+                .expect("sensor read more often than scripted")
+                .to_string()),
+            _ => Err(Error::subtype_not_supported(sub_type)),
+        }
+    }
+}
+
+impl TempSensor for ScriptedTempSensor {}
+
+#[test]
+fn test_read_stable() {
+    use crate::units::Temperature;
+    use std::time::Duration;
+
+    let temp = ScriptedTempSensor::new(&[
+        "60000", "50000", "40000", "40050", "39950", "40000", "40000", "99999",
+    ]);
+
+    let stable = temp
+        .read_stable(Temperature::from_raw("100").unwrap(), 5, Duration::ZERO, 10)
+        .unwrap();
+
+    assert_eq!(Temperature::from_raw("40000").unwrap(), stable);
+    assert_eq!(1, temp.remaining());
+
+    let temp = ScriptedTempSensor::new(&["40000", "50000", "40000", "50000"]);
+
+    assert!(matches!(
+        temp.read_stable(Temperature::from_raw("100").unwrap(), 2, Duration::ZERO, 4),
+        Err(Error::Unstable { attempts: 4 })
+    ));
+    assert_eq!(0, temp.remaining());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_write_thresholds() {
+    use crate::units::Voltage;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_voltage(0, 1100);
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let voltage = VoltageSensorStruct::parse(hwmon, 0).unwrap();
+
+    let volts = |raw: &str| Voltage::from_raw(raw).unwrap();
+
+    voltage
+        .write_thresholds(
+            Some(volts("900")),
+            Some(volts("1000")),
+            Some(volts("1200")),
+            Some(volts("1300")),
+        )
+        .unwrap();
+
+    assert_eq!(volts("900"), voltage.read_lcrit().unwrap());
+    assert_eq!(volts("1300"), voltage.read_crit().unwrap());
+
+    assert!(matches!(
+        voltage.write_thresholds(Some(volts("1100")), Some(volts("1000")), None, None),
+        Err(Error::InvalidOrdering {
+            lower: SensorSubFunctionType::LowCrit,
+            upper: SensorSubFunctionType::Min
+        })
+    ));
+    assert!(matches!(
+        voltage.write_thresholds(None, Some(volts("1250")), None, None),
+        Err(Error::InvalidOrdering {
+            lower: SensorSubFunctionType::Min,
+            upper: SensorSubFunctionType::Max
+        })
+    ));
+    assert_eq!(volts("1000"), voltage.read_min().unwrap());
+
+    voltage
+        .write_thresholds(None, Some(volts("1100")), None, None)
+        .unwrap();
+
+    assert_eq!(volts("1100"), voltage.read_min().unwrap());
+}
+
+#[test]
+fn test_try_read_input() {
+    use crate::units::Temperature;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 50000, "temp2");
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp1 = TempSensorStruct::parse(hwmon, 1).unwrap();
+    let temp2 = TempSensorStruct::parse(hwmon, 2).unwrap();
+
+    std::fs::remove_file(test_dir.path().join("hwmon0/temp2_input")).unwrap();
+
+    assert_eq!(
+        Some(Temperature::from_raw("40000").unwrap()),
+        temp1.try_read_input().unwrap()
+    );
+    assert_eq!(None, temp2.try_read_input().unwrap());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_set_average_interval_clamped() {
+    use crate::sensors::sync_sensors::power::*;
+    use std::time::Duration;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_power(1, 15_000_000);
+
+    std::fs::write(
+        test_dir.path().join("hwmon0/power1_average_interval_min"),
+        "100",
+    )
+    .unwrap();
+    std::fs::write(
+        test_dir.path().join("hwmon0/power1_average_interval_max"),
+        "1000",
+    )
+    .unwrap();
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let power = PowerSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!(
+        Duration::from_millis(1000),
+        power
+            .set_average_interval_clamped(Duration::from_secs(5))
+            .unwrap()
+    );
+    assert_eq!(
+        Duration::from_millis(1000),
+        power.read_average_interval().unwrap()
+    );
+
+    assert_eq!(
+        Duration::from_millis(100),
+        power
+            .set_average_interval_clamped(Duration::from_millis(10))
+            .unwrap()
+    );
+    assert_eq!(
+        Duration::from_millis(100),
+        power.read_average_interval().unwrap()
+    );
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_power_max_interval() {
+    use crate::sensors::sync_sensors::power::*;
+    use std::time::Duration;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "intel-rapl").add_power(1, 15_000_000);
+
+    std::fs::write(test_dir.path().join("hwmon0/power1_max"), "25000000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/power1_max_interval"), "28000").unwrap();
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let power = PowerSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!(Duration::from_secs(28), power.read_max_interval().unwrap());
+    assert!(power.read_average_interval().is_err());
+
+    power.write_max_interval(Duration::from_secs(1)).unwrap();
+
+    assert_eq!(
+        "1000",
+        std::fs::read_to_string(test_dir.path().join("hwmon0/power1_max_interval")).unwrap()
+    );
+    assert_eq!(Duration::from_secs(1), power.read_max_interval().unwrap());
+}
+
+#[test]
+fn test_pwm_read_state() {
+    use crate::units::{Pwm, PwmEnable};
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_pwm(1, true, false);
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let pwm = PwmSensorStruct::parse(hwmon, 1).unwrap();
+
+    let state = pwm.read_state().unwrap();
+
+    assert_eq!(Some(Pwm::from_raw("0").unwrap()), state.pwm);
+    assert_eq!(Some(PwmEnable::BiosControl), state.enable);
+    assert_eq!(None, state.mode);
+    assert_eq!(None, state.frequency);
+}
+
+#[test]
+fn test_read_input_scaled() {
+    use crate::units::Voltage;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_voltage(1, 1650);
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let voltage = VoltageSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!(
+        Voltage::from_raw("3300").unwrap(),
+        voltage.read_input_scaled(2.0).unwrap()
+    );
+    assert!(voltage.read_input_scaled(f64::NAN).is_err());
+    assert!(voltage.read_input_scaled(-2.0).is_err());
+    #[cfg(not(feature = "uom_units"))]
+    assert!(voltage.read_input_scaled(1e9).is_err());
+}
+
+#[test]
+fn test_min_hyst_absolute() {
+    use crate::units::Temperature;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_min"), "20000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp1_min_hyst"), "2000").unwrap();
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!(
+        Temperature::from_raw("22000").unwrap(),
+        temp.min_hyst_absolute().unwrap()
+    );
+}
+
+#[test]
+fn test_auto_point_count() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_pwm(1, true, true)
+        .add_pwm(2, true, true);
+
+    for point in 1..=3 {
+        std::fs::write(
+            test_dir
+                .path()
+                .join(format!("hwmon0/pwm1_auto_point{}_temp", point)),
+            "40000",
+        )
+        .unwrap();
+    }
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    assert_eq!(
+        3,
+        PwmSensorStruct::parse(hwmon, 1).unwrap().auto_point_count()
+    );
+    assert_eq!(
+        0,
+        PwmSensorStruct::parse(hwmon, 2).unwrap().auto_point_count()
+    );
+}
+
+#[test]
+fn test_read_burst_stats() {
+    use crate::units::Temperature;
+    use std::time::Duration;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    let hwmons: Hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    let input_path = test_dir.path().join("hwmon0/temp1_input");
+    let writer = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+
+        // Replace the file atomically, so no partially written value is read.
+        let tmp_path = input_path.with_extension("tmp");
+        std::fs::write(&tmp_path, "44000").unwrap();
+        std::fs::rename(&tmp_path, &input_path).unwrap();
+    });
+
+    let stats = temp
+        .read_burst_stats(10, Duration::from_millis(20))
+        .unwrap();
+
+    writer.join().unwrap();
+
+    assert_eq!(Temperature::from_raw("40000").unwrap(), stats.min);
+    assert_eq!(Temperature::from_raw("44000").unwrap(), stats.max);
+    assert!(stats.min < stats.mean && stats.mean < stats.max);
+    assert!(stats.std_dev > 0.0);
+}
+
+#[test]
+fn test_overcurrent() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_current(1, 1000);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let curr = CurrentSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert!(!curr.overcurrent().unwrap());
+
+    for alarm in ["alarm", "max_alarm", "crit_alarm"] {
+        let alarm_path = hwmon.path().join(format!("curr1_{}", alarm));
+
+        std::fs::write(&alarm_path, "0\n").unwrap();
+        assert!(!curr.overcurrent().unwrap());
+
+        std::fs::write(&alarm_path, "1\n").unwrap();
+        assert!(curr.overcurrent().unwrap());
+
+        std::fs::remove_file(&alarm_path).unwrap();
+    }
+}
+
+#[test]
+fn test_input_path() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert!(temp.input_path().ends_with("temp1_input"));
+    assert!(temp.input_path().is_file());
+}
+
+#[test]
+fn test_read_raw_bytes() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    std::fs::write(hwmon.path().join("temp1_label"), b"CPU\xff\n").unwrap();
+
+    assert_eq!(
+        b"CPU\xff\n".to_vec(),
+        temp.read_raw_bytes(SensorSubFunctionType::Label).unwrap()
+    );
+    assert_eq!(
+        "CPU\u{fffd}",
+        temp.read_raw(SensorSubFunctionType::Label).unwrap()
+    );
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_supports_reset_history() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 50000, "temp2");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_reset_history"), "0").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    assert!(TempSensorStruct::parse(hwmon, 1)
+        .unwrap()
+        .supports_reset_history());
+    assert!(!TempSensorStruct::parse(hwmon, 2)
+        .unwrap()
+        .supports_reset_history());
+}
+
+#[test]
+fn test_cap_clear_level() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_power(1, 15_000_000);
+
+    std::fs::write(test_dir.path().join("hwmon0/power1_cap"), "100000000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/power1_cap_hyst"), "5000000").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let power = PowerSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!(
+        "95000000",
+        power.cap_clear_level().unwrap().to_raw().as_ref()
+    );
+}
+
+#[test]
+fn test_read_all_fields() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_max"), "80000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp1_type"), "4").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp1_max_alarm"), "garbage").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    let readings = temp.read_all_fields();
+
+    assert!(readings.input.is_ok());
+    assert!(readings.max.is_ok());
+    assert_eq!(
+        crate::units::TempType::Thermistor,
+        readings.temp_type.unwrap()
+    );
+    assert!(matches!(
+        readings.crit,
+        Err(crate::sensors::Error::SubtypeNotSupported { .. })
+    ));
+    assert!(matches!(
+        readings.max_alarm,
+        Err(crate::sensors::Error::UnitError { .. })
+    ));
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_set_target_checked() {
+    use crate::units::Raw;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_fan(1, 60)
+        .add_fan(2, 60)
+        .add_pwm(1, true, true);
+
+    std::fs::write(test_dir.path().join("hwmon0/fan1_target"), "0").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/fan2_target"), "0").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/pwm1_enable"), "1").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let fan = FanSensorStruct::parse(hwmon, 1).unwrap();
+    let target = crate::units::AngularVelocity::from_raw("1200").unwrap();
+
+    assert!(matches!(
+        fan.set_target_checked(target),
+        Err(crate::sensors::Error::ClosedLoopRequired { .. })
+    ));
+    assert_eq!(
+        "0",
+        std::fs::read_to_string(test_dir.path().join("hwmon0/fan1_target")).unwrap()
+    );
+
+    std::fs::write(test_dir.path().join("hwmon0/pwm1_enable"), "2").unwrap();
+    fan.set_target_checked(target).unwrap();
+    assert_eq!(
+        "1200",
+        std::fs::read_to_string(test_dir.path().join("hwmon0/fan1_target")).unwrap()
+    );
+
+    FanSensorStruct::parse(hwmon, 2)
+        .unwrap()
+        .set_target_checked(target)
+        .unwrap();
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_write_pwm_floored() {
+    use crate::units::Pwm;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_pwm(1, true, true);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let pwm = PwmSensorStruct::parse(hwmon, 1).unwrap();
+    let floor = Pwm::from_u8(60);
+
+    pwm.write_pwm_floored(Pwm::from_u8(200), floor).unwrap();
+    assert_eq!(200, pwm.read_pwm().unwrap().as_u8());
+
+    pwm.write_pwm_floored(Pwm::from_u8(20), floor).unwrap();
+    assert_eq!(60, pwm.read_pwm().unwrap().as_u8());
+
+    pwm.write_pwm_floored(Pwm::from_u8(0), floor).unwrap();
+    assert_eq!(0, pwm.read_pwm().unwrap().as_u8());
+}
+
+#[test]
+fn test_headroom() {
+    use crate::units::Raw;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 95000, "temp2");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_crit"), "100000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp1_max"), "80000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp2_max"), "90000").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    assert_eq!(
+        "60000",
+        TempSensorStruct::parse(hwmon, 1)
+            .unwrap()
+            .headroom()
+            .unwrap()
+            .to_raw()
+    );
+    assert_eq!(
+        "-5000",
+        TempSensorStruct::parse(hwmon, 2)
+            .unwrap()
+            .headroom()
+            .unwrap()
+            .to_raw()
+    );
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_voltage_average_interval() {
+    use std::time::Duration;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_voltage(0, 1200);
+
+    std::fs::write(test_dir.path().join("hwmon0/in0_average_interval"), "100").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let voltage = VoltageSensorStruct::parse(hwmon, 0).unwrap();
+
+    assert_eq!(
+        Duration::from_millis(100),
+        voltage.read_average_interval().unwrap()
+    );
+
+    voltage
+        .write_average_interval(Duration::from_millis(250))
+        .unwrap();
+
+    assert_eq!(
+        Duration::from_millis(250),
+        voltage.read_average_interval().unwrap()
+    );
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_can_write() {
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_current(1, 1000);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let curr = CurrentSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert!(!curr.can_write());
+
+    // A writeable min limit doesn't help, if the max limit a tool writes to is missing.
+    std::fs::write(hwmon.path().join("curr1_min"), "0").unwrap();
+
+    assert!(!curr.can_write());
+
+    let max_path = hwmon.path().join("curr1_max");
+    std::fs::write(&max_path, "2000").unwrap();
+
+    assert!(curr.can_write());
+
+    std::fs::set_permissions(&max_path, std::fs::Permissions::from_mode(0o444)).unwrap();
+
+    // Root can open read-only files for writing, so the check is only meaningful for other users.
+    // Files are owned by the effective user that created them.
+    if max_path.metadata().unwrap().uid() != 0 {
+        assert!(!curr.can_write());
+    }
+
+    std::fs::set_permissions(&max_path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+    assert!(curr.can_write());
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "system").add_pwm(1, true, true);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(1).unwrap();
+    let pwm = PwmSensorStruct::parse(hwmon, 1).unwrap();
+    let pwm_path = hwmon.path().join("pwm1");
+
+    assert!(pwm.can_write());
+
+    std::fs::set_permissions(&pwm_path, std::fs::Permissions::from_mode(0o444)).unwrap();
+
+    // The writeable pwm1_enable doesn't make the pwm writeable.
+    if pwm_path.metadata().unwrap().uid() != 0 {
+        assert!(!pwm.can_write());
+    }
+}
+
+#[test]
+fn test_duty_and_frequency() {
+    use crate::units::{Frequency, Raw};
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_pwm(1, true, true)
+        .add_pwm(2, true, true);
+
+    std::fs::write(test_dir.path().join("hwmon0/pwm1"), "51").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/pwm1_freq"), "25000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/pwm2"), "255").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let (duty, frequency) = PwmSensorStruct::parse(hwmon, 1)
+        .unwrap()
+        .duty_and_frequency()
+        .unwrap();
+
+    assert!((duty - 0.2).abs() < f64::EPSILON);
+    assert_eq!(Some(Frequency::from_raw("25000").unwrap()), frequency);
+
+    let (duty, frequency) = PwmSensorStruct::parse(hwmon, 2)
+        .unwrap()
+        .duty_and_frequency()
+        .unwrap();
+
+    assert!((duty - 1.0).abs() < f64::EPSILON);
+    assert!(frequency.is_none());
+}
+
+#[test]
+fn test_with_transform() {
+    use crate::units::Raw;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "k10temp").add_temp(1, 40000, "Tctl");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_max"), "70000").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+    let transformed = temp.clone().with_transform(|raw| raw + 5000);
+
+    assert_eq!("45000", transformed.read_input().unwrap().to_raw());
+    assert_eq!("70000", transformed.read_max().unwrap().to_raw());
+    assert_eq!("Tctl", transformed.name());
+    assert_eq!("40000", temp.read_input().unwrap().to_raw());
+}
+
+#[test]
+fn test_alarm_polarity() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "inverted").add_temp(1, 40000, "temp1");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "system").add_temp(1, 40000, "temp1");
+
+    for hwmon in ["hwmon0", "hwmon1"] {
+        std::fs::write(test_dir.path().join(hwmon).join("temp1_alarm"), "0").unwrap();
+        std::fs::write(test_dir.path().join(hwmon).join("temp1_crit_alarm"), "1").unwrap();
+    }
+
+    let mut hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let temp = hwmons.hwmon_by_index(0).unwrap().temp(1).unwrap();
+
+    assert!(!temp.alarm_active_low());
+    assert!(!temp.read_alarm().unwrap());
+    assert!(temp.read_crit_alarm().unwrap());
+
+    hwmons.set_alarm_polarity("inverted", true);
+
+    let temp = hwmons.hwmon_by_index(0).unwrap().temp(1).unwrap();
+
+    assert!(temp.alarm_active_low());
+    assert!(temp.read_alarm().unwrap());
+    assert!(!temp.read_crit_alarm().unwrap());
+
+    let untouched = hwmons.hwmon_by_index(1).unwrap().temp(1).unwrap();
+
+    assert!(!untouched.read_alarm().unwrap());
+}
+
+#[test]
+fn test_sensor_alarm_polarity() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_temp(2, 40000, "temp2");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_alarm"), "0").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp2_alarm"), "0").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    let mut inverted = TempSensorStruct::parse(hwmon, 1).unwrap();
+    let normal = TempSensorStruct::parse(hwmon, 2).unwrap();
+
+    inverted.set_alarm_polarity(true);
+
+    assert!(inverted.alarm_active_low());
+    assert!(inverted.read_alarm().unwrap());
+    assert!(!normal.alarm_active_low());
+    assert!(!normal.read_alarm().unwrap());
+
+    inverted.set_alarm_polarity(false);
+
+    assert!(!inverted.read_alarm().unwrap());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_write_threshold_str() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_max"), "70000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp1_crit"), "90000").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    temp.write_threshold_str(SensorSubFunctionType::Max, "85C")
+        .unwrap();
+    assert_eq!(
+        "85000",
+        std::fs::read_to_string(test_dir.path().join("hwmon0/temp1_max"))
+            .unwrap()
+            .trim()
+    );
+
+    temp.write_threshold_str(SensorSubFunctionType::Crit, "185F")
+        .unwrap();
+    assert_eq!(
+        "85000",
+        std::fs::read_to_string(test_dir.path().join("hwmon0/temp1_crit"))
+            .unwrap()
+            .trim()
+    );
+
+    assert!(temp
+        .write_threshold_str(SensorSubFunctionType::Enable, "85C")
+        .is_err());
+    assert!(temp
+        .write_threshold_str(SensorSubFunctionType::Max, "85K")
+        .is_err());
+}
+
+#[test]
+fn test_power_input_peaks() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_power(1, 15_000_000);
+
+    std::fs::write(
+        test_dir.path().join("hwmon0/power1_input_highest"),
+        "42000000",
+    )
+    .unwrap();
+    std::fs::write(
+        test_dir.path().join("hwmon0/power1_input_lowest"),
+        "3000000",
+    )
+    .unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let power = PowerSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!(
+        "42000000",
+        power.read_input_highest().unwrap().to_raw().as_ref()
+    );
+    assert_eq!(
+        "3000000",
+        power.read_input_lowest().unwrap().to_raw().as_ref()
+    );
+
+    std::fs::remove_file(test_dir.path().join("hwmon0/power1_input_lowest")).unwrap();
+    assert!(power.read_input_lowest().is_err());
+}
+
+#[test]
+fn test_read_first_available() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_max"), "80000").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    let (sub, value) = temp
+        .read_first_available(&[
+            SensorSubFunctionType::Crit,
+            SensorSubFunctionType::Max,
+            SensorSubFunctionType::Input,
+        ])
+        .unwrap();
+    assert_eq!(SensorSubFunctionType::Max, sub);
+    assert_eq!("80000", value.to_raw().as_ref());
+
+    assert!(matches!(
+        temp.read_first_available(&[SensorSubFunctionType::Crit, SensorSubFunctionType::LowCrit]),
+        Err(Error::SubtypeNotSupported {
+            sub_type: SensorSubFunctionType::Crit
+        })
+    ));
+}
+
+#[test]
+fn test_into_smoothed() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 0, "temp1");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert!(temp.clone().into_smoothed(0.0).is_err());
+    assert!(temp.clone().into_smoothed(1.5).is_err());
+
+    let smoothed = temp.into_smoothed(0.5).unwrap();
+    assert_eq!("0", smoothed.read_input().unwrap().to_raw().as_ref());
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_input"), "40000").unwrap();
+
+    let mut previous = 0;
+    for _ in 0..20 {
+        let current = smoothed
+            .read_input()
+            .unwrap()
+            .to_raw()
+            .parse::<i64>()
+            .unwrap();
+        assert!(current >= previous && current <= 40000);
+        previous = current;
+    }
+    assert!(40000 - previous <= 1);
+
+    assert_eq!(
+        "40000",
+        smoothed.read_unsmoothed_input().unwrap().to_raw().as_ref()
+    );
+    assert_eq!(
+        "40000",
+        smoothed.read_raw(SensorSubFunctionType::Input).unwrap()
+    );
+}
+
+#[test]
+fn test_with_scale() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 42, "temp1");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_crit"), "95").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp1_crit_alarm"), "1").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert!(temp.clone().with_scale(0).is_err());
+
+    let scaled = temp.with_scale(1).unwrap();
+
+    assert_eq!("42000", scaled.read_input().unwrap().to_raw().as_ref());
+    assert_eq!("95000", scaled.read_crit().unwrap().to_raw().as_ref());
+    assert!(scaled.read_crit_alarm().unwrap());
+}
+
+#[test]
+fn test_read_crit_from_thermal_zone() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "acpitz")
+        .add_temp(1, 40000, "temp1")
+        .add_device("thermal", "virtual/thermal/thermal_zone0");
+    VirtualHwmonBuilder::create(test_dir.path(), 1, "system").add_temp(1, 40000, "temp1");
+
+    let zone = test_dir
+        .path()
+        .join("devices/virtual/thermal/thermal_zone0");
+    std::fs::write(zone.join("trip_point_0_type"), "passive\n").unwrap();
+    std::fs::write(zone.join("trip_point_0_temp"), "80000\n").unwrap();
+    std::fs::write(zone.join("trip_point_1_type"), "critical\n").unwrap();
+    std::fs::write(zone.join("trip_point_1_temp"), "105000\n").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+
+    let temp = TempSensorStruct::parse(hwmons.hwmon_by_index(0).unwrap(), 1).unwrap();
+    assert!(temp.read_crit().is_err());
+    assert_eq!(
+        "105000",
+        temp.read_crit_from_thermal_zone()
+            .unwrap()
+            .unwrap()
+            .to_raw()
+            .as_ref()
+    );
+
+    let temp = TempSensorStruct::parse(hwmons.hwmon_by_index(1).unwrap(), 1).unwrap();
+    assert!(temp.read_crit_from_thermal_zone().unwrap().is_none());
+}
+
+#[test]
+fn test_csv_logger() {
+    use crate::sensors::sync_sensors::csv::CsvLogger;
+    use crate::sensors::SensorKind;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 40000, "temp1")
+        .add_fan(1, 1200);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let csv_path = test_dir.path().join("log.csv");
+    let subfunctions = [SensorSubFunctionType::Input, SensorSubFunctionType::Max];
+
+    let sensors = || -> Vec<Box<dyn DynSensor>> {
+        vec![
+            Box::new(hwmon.any_sensor(SensorKind::Temp, 1).unwrap()),
+            Box::new(hwmon.any_sensor(SensorKind::Fan, 1).unwrap()),
+        ]
+    };
+
+    let mut logger = CsvLogger::new(&csv_path, sensors(), &subfunctions).unwrap();
+    logger.append().unwrap();
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_input"), "45000").unwrap();
+
+    let mut logger = CsvLogger::new(&csv_path, sensors(), &subfunctions).unwrap();
+    logger.append().unwrap();
+
+    let contents = std::fs::read_to_string(&csv_path).unwrap();
+    let rows: Vec<Vec<&str>> = contents
+        .lines()
+        .map(|line| line.split(',').collect())
+        .collect();
+
+    assert_eq!(3, rows.len());
+    assert_eq!(
+        vec![
+            "timestamp",
+            "hwmon0/temp1_input",
+            "hwmon0/temp1_max",
+            "hwmon0/fan1_input",
+            "hwmon0/fan1_max",
+        ],
+        rows[0]
+    );
+    assert_eq!(vec!["40000", "", "1200", ""], rows[1][1..]);
+    assert_eq!(vec!["45000", "", "1200", ""], rows[2][1..]);
+    assert!(rows[1][0].parse::<f64>().unwrap() <= rows[2][0].parse::<f64>().unwrap());
+}
+
+#[test]
+fn test_pwm_control_state() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_pwm(1, true, true);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let pwm = PwmSensorStruct::parse(hwmon, 1).unwrap();
+    let enable_path = test_dir.path().join("hwmon0/pwm1_enable");
+
+    for (raw, manual, full_speed) in [("0", false, true), ("1", true, false), ("2", false, false)] {
+        std::fs::write(&enable_path, raw).unwrap();
+
+        assert_eq!(manual, pwm.is_under_manual_control().unwrap());
+        assert_eq!(full_speed, pwm.is_full_speed().unwrap());
+    }
+
+    std::fs::remove_file(&enable_path).unwrap();
+    assert!(pwm.is_under_manual_control().is_err());
+}
+
+#[test]
+fn test_read_enable_raw() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    let enable_path = test_dir.path().join("hwmon0/temp1_enable");
+    std::fs::write(&enable_path, "2\n").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!(2, temp.read_enable_raw().unwrap());
+    assert!(temp.read_enable().unwrap());
+
+    std::fs::write(&enable_path, "0\n").unwrap();
+
+    assert_eq!(0, temp.read_enable_raw().unwrap());
+    assert!(!temp.read_enable().unwrap());
+}
+
+#[test]
+fn test_read_input_signed16() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 65036, "temp1");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!(
+        "-500",
+        temp.read_input_signed16().unwrap().to_raw().as_ref()
+    );
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_input"), "25000").unwrap();
+    assert_eq!(
+        "25000",
+        temp.read_input_signed16().unwrap().to_raw().as_ref()
+    );
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_input"), "70000").unwrap();
+    assert!(temp.read_input_signed16().is_err());
+}
+
+#[test]
+fn test_read_input_status() {
+    use crate::sensors::LimitStatus;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_temp(1, 50000, "temp1")
+        .add_temp(2, 50000, "temp2");
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_min"), "10000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp1_max"), "80000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/temp1_crit"), "95000").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    let (input, status) = temp.read_input_status().unwrap();
+    assert_eq!("50000", input.to_raw().as_ref());
+    assert_eq!(LimitStatus::Ok, status);
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_input"), "85000").unwrap();
+    assert_eq!(LimitStatus::AboveMax, temp.read_input_status().unwrap().1);
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_input"), "100000").unwrap();
+    assert_eq!(LimitStatus::AboveCrit, temp.read_input_status().unwrap().1);
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_input"), "5000").unwrap();
+    assert_eq!(LimitStatus::BelowMin, temp.read_input_status().unwrap().1);
+
+    let temp = TempSensorStruct::parse(hwmon, 2).unwrap();
+    assert_eq!(LimitStatus::Ok, temp.read_input_status().unwrap().1);
+}
+
+#[test]
+fn test_danger_threshold() {
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 50000, "temp1");
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let temp = TempSensorStruct::parse(hwmon, 1).unwrap();
+
+    assert_eq!(None, temp.danger_threshold().unwrap());
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_max"), "80000").unwrap();
+    assert_eq!(
+        "80000",
+        temp.danger_threshold().unwrap().unwrap().to_raw().as_ref()
+    );
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_crit"), "95000").unwrap();
+    assert_eq!(
+        "95000",
+        temp.danger_threshold().unwrap().unwrap().to_raw().as_ref()
+    );
+
+    std::fs::write(test_dir.path().join("hwmon0/temp1_emergency"), "105000").unwrap();
+    assert_eq!(
+        "105000",
+        temp.danger_threshold().unwrap().unwrap().to_raw().as_ref()
+    );
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_write_pwm_clamped_info() {
+    use crate::units::Pwm;
+    use std::path::Path;
+
+    /// Pwm that rounds written values to the nearest multiple of 8 like some chips do.
+    #[derive(Debug)]
+    struct RoundingPwm(PwmSensorStruct);
+
+    impl Sensor for RoundingPwm {
+        type Value = Pwm;
+
+        fn base(&self) -> &'static str {
+            self.0.base()
+        }
+
+        fn index(&self) -> u16 {
+            self.0.index()
+        }
+
+        fn hwmon_path(&self) -> &Path {
+            self.0.hwmon_path()
+        }
+
+        fn alarm_active_low(&self) -> bool {
+            self.0.alarm_active_low()
+        }
+
+        fn set_alarm_polarity(&mut self, active_low: bool) {
+            self.0.set_alarm_polarity(active_low);
+        }
+    }
+
+    impl WriteableSensor for RoundingPwm {
+        fn write_raw(&self, sub_type: SensorSubFunctionType, raw_value: &str) -> Result<()> {
+            if sub_type != SensorSubFunctionType::Pwm {
+                return self.0.write_raw(sub_type, raw_value);
+            }
+
+            let value = raw_value.parse::<u32>().unwrap();
+            let rounded = ((value + 4) / 8 * 8).min(255);
+
+            self.0.write_raw(sub_type, &rounded.to_string())
+        }
+    }
+
+    impl PwmSensor for RoundingPwm {}
+
+    impl WriteablePwmSensor for RoundingPwm {}
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_pwm(1, true, true);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let pwm = RoundingPwm(PwmSensorStruct::parse(hwmon, 1).unwrap());
+
+    let info = pwm.write_pwm_clamped_info(Pwm::from_u8(125)).unwrap();
+    assert_eq!(125, info.requested.as_u8());
+    assert_eq!(128, info.stored.as_u8());
+    assert_eq!(3, info.delta);
+    assert!(info.is_clamped());
+
+    let info = pwm.write_pwm_clamped_info(Pwm::from_u8(123)).unwrap();
+    assert_eq!(120, info.stored.as_u8());
+    assert_eq!(-3, info.delta);
+
+    let info = pwm.write_pwm_clamped_info(Pwm::from_u8(64)).unwrap();
+    assert_eq!(0, info.delta);
+    assert!(!info.is_clamped());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_write_pwm_temporary() {
+    use crate::units::{Pwm, PwmEnable};
+    use std::time::Duration;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_pwm(1, true, true);
+    std::fs::write(test_dir.path().join("hwmon0/pwm1"), "100").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let pwm = PwmSensorStruct::parse(hwmon, 1).unwrap();
+
+    let handle = pwm
+        .write_pwm_temporary(Pwm::from_u8(255), Duration::from_millis(50))
+        .unwrap();
+
+    assert_eq!(255, pwm.read_pwm().unwrap().as_u8());
+    assert_eq!(PwmEnable::ManualControl, pwm.read_enable().unwrap());
+
+    handle.join().unwrap().unwrap();
+
+    assert_eq!(100, pwm.read_pwm().unwrap().as_u8());
+    assert_eq!(PwmEnable::BiosControl, pwm.read_enable().unwrap());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_write_pwm_temporary_vendor_mode() {
+    use crate::units::Pwm;
+    use std::time::Duration;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "nct6775").add_pwm(1, true, true);
+
+    let enable_path = test_dir.path().join("hwmon0/pwm1_enable");
+    std::fs::write(&enable_path, "5").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let pwm = PwmSensorStruct::parse(hwmon, 1).unwrap();
+
+    let handle = pwm
+        .write_pwm_temporary(Pwm::from_u8(255), Duration::ZERO)
+        .unwrap();
+    handle.join().unwrap().unwrap();
+
+    assert_eq!("5", std::fs::read_to_string(&enable_path).unwrap().trim());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_write_pwm_temporary_failures() {
+    use crate::units::{Pwm, PwmEnable};
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+    use std::time::Duration;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_pwm(1, true, true);
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let pwm = PwmSensorStruct::parse(hwmon, 1).unwrap();
+    let pwm_path = hwmon.path().join("pwm1");
+
+    // A failing override hands control back. Root can write read-only files, so this is only
+    // meaningful for other users.
+    std::fs::set_permissions(&pwm_path, std::fs::Permissions::from_mode(0o444)).unwrap();
+
+    if pwm_path.metadata().unwrap().uid() != 0 {
+        assert!(pwm
+            .write_pwm_temporary(Pwm::from_u8(255), Duration::ZERO)
+            .is_err());
+        assert_eq!(PwmEnable::BiosControl, pwm.read_enable().unwrap());
+    }
+
+    std::fs::set_permissions(&pwm_path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+    // A failing pwm restore doesn't keep the enable from being restored.
+    let handle = pwm
+        .write_pwm_temporary(Pwm::from_u8(255), Duration::from_secs(1))
+        .unwrap();
+
+    std::fs::remove_file(&pwm_path).unwrap();
+    std::fs::create_dir(&pwm_path).unwrap();
+
+    assert!(handle.join().unwrap().is_err());
+    assert_eq!(PwmEnable::BiosControl, pwm.read_enable().unwrap());
+}
+
+#[test]
+fn test_fan_health() {
+    use crate::sensors::FanHealth;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_fan(1, 1200)
+        .add_fan(2, 0)
+        .add_fan(3, 1200)
+        .add_fan(4, 0);
+
+    std::fs::write(test_dir.path().join("hwmon0/fan1_min"), "600").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/fan2_min"), "600").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/fan3_fault"), "1").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let healthy = FanSensorStruct::parse(hwmon, 1).unwrap();
+    let stalled = FanSensorStruct::parse(hwmon, 2).unwrap();
+    let faulty = FanSensorStruct::parse(hwmon, 3).unwrap();
+    let stopped = FanSensorStruct::parse(hwmon, 4).unwrap();
+
+    assert_eq!(FanHealth::Ok, healthy.health().unwrap());
+    assert_eq!(FanHealth::Failed, stalled.health().unwrap());
+    assert_eq!(FanHealth::Failed, faulty.health().unwrap());
+    assert_eq!(FanHealth::Ok, stopped.health().unwrap());
+
+    std::fs::write(test_dir.path().join("hwmon0/fan1_alarm"), "1").unwrap();
+    assert_eq!(FanHealth::Degraded, healthy.health().unwrap());
+}
+
+#[cfg(feature = "writeable")]
+#[test]
+fn test_write_div_validation() {
+    use crate::units::FanDivisor;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_fan(1, 1200);
+
+    let div_path = test_dir.path().join("hwmon0/fan1_div");
+    std::fs::write(&div_path, "2").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let fan = FanSensorStruct::parse(hwmon, 1).unwrap();
+
+    fan.write_div(FanDivisor::try_new(8).unwrap()).unwrap();
+    assert_eq!("8", std::fs::read_to_string(&div_path).unwrap());
+
+    assert!(matches!(
+        fan.write_div(FanDivisor::try_from_value(16u32).unwrap()),
+        Err(Error::Unit { path, .. }) if path == div_path
+    ));
+    assert_eq!("8", std::fs::read_to_string(&div_path).unwrap());
+}
+
+#[test]
+fn test_power_effective_limit() {
+    use crate::sensors::sync_sensors::power::*;
+    use crate::units::Raw;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_power(1, 15_000_000)
+        .add_power(2, 15_000_000)
+        .add_power(3, 15_000_000)
+        .add_power(4, 15_000_000);
+
+    std::fs::write(test_dir.path().join("hwmon0/power1_cap"), "45000000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/power2_crit"), "65000000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/power3_cap"), "45000000").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/power3_crit"), "35000000").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+    for (index, limit) in [
+        (1, Some("45000000")),
+        (2, Some("65000000")),
+        (3, Some("35000000")),
+        (4, None),
+    ] {
+        let power = PowerSensorStruct::parse(hwmon, index).unwrap();
+
+        assert_eq!(
+            limit.map(String::from),
+            power
+                .effective_limit()
+                .unwrap()
+                .map(|limit| limit.to_raw().to_string())
+        );
+    }
+}
+
+#[test]
+fn test_fan_speed_warning() {
+    use crate::sensors::FanSpeedWarning;
+
+    let test_dir = TempDir::new().unwrap();
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system")
+        .add_fan(1, 1200)
+        .add_fan(2, 1200);
+
+    std::fs::write(test_dir.path().join("hwmon0/fan1_min_alarm"), "0").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/fan1_max_alarm"), "0").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/fan2_min"), "600").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/fan2_max"), "2000").unwrap();
+
+    let hwmons = Hwmons::parse_path(test_dir.path()).unwrap();
+    let hwmon = hwmons.hwmon_by_index(0).unwrap();
+    let alarms = FanSensorStruct::parse(hwmon, 1).unwrap();
+    let limits = FanSensorStruct::parse(hwmon, 2).unwrap();
+
+    assert_eq!(None, alarms.speed_warning().unwrap());
+    assert_eq!(None, limits.speed_warning().unwrap());
+
+    std::fs::write(test_dir.path().join("hwmon0/fan1_min_alarm"), "1").unwrap();
+    assert_eq!(
+        Some(FanSpeedWarning::TooSlow),
+        alarms.speed_warning().unwrap()
+    );
+
+    std::fs::write(test_dir.path().join("hwmon0/fan1_min_alarm"), "0").unwrap();
+    std::fs::write(test_dir.path().join("hwmon0/fan1_max_alarm"), "1").unwrap();
+    assert_eq!(
+        Some(FanSpeedWarning::TooFast),
+        alarms.speed_warning().unwrap()
+    );
+
+    std::fs::write(test_dir.path().join("hwmon0/fan2_input"), "300").unwrap();
+    assert_eq!(
+        Some(FanSpeedWarning::TooSlow),
+        limits.speed_warning().unwrap()
+    );
+
+    std::fs::write(test_dir.path().join("hwmon0/fan2_input"), "2500").unwrap();
+    assert_eq!(
+        Some(FanSpeedWarning::TooFast),
+        limits.speed_warning().unwrap()
+    );
+}
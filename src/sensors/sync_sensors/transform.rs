@@ -0,0 +1,90 @@
+//! Module containing sensors whose input is transformed before unit conversion.
+
+use super::*;
+use super::{
+    curr::CurrentSensor, energy::EnergySensor, fan::FanSensor, humidity::HumiditySensor,
+    intrusion::IntrusionSensor, power::PowerSensor, pwm::PwmSensor, temp::TempSensor,
+    voltage::VoltageSensor,
+};
+use crate::units::Error as UnitError;
+
+use std::path::Path;
+
+/// Wrapper around a sensor that applies a transform to the raw integer value of its input
+/// before it is converted to the sensor's unit.
+/// This can be used to correct known offsets or scaling errors of a chip.
+/// All other subfunctions are read unchanged.
+#[derive(Debug, Clone)]
+pub struct TransformedSensor<S> {
+    sensor: S,
+    transform: fn(i64) -> i64,
+}
+
+impl<S> TransformedSensor<S> {
+    pub(crate) fn new(sensor: S, transform: fn(i64) -> i64) -> Self {
+        Self { sensor, transform }
+    }
+
+    /// Returns the wrapped sensor.
+    pub fn inner(&self) -> &S {
+        &self.sensor
+    }
+}
+
+impl<S: Sensor> Sensor for TransformedSensor<S> {
+    type Value = S::Value;
+
+    fn base(&self) -> &'static str {
+        self.sensor.base()
+    }
+
+    fn index(&self) -> u16 {
+        self.sensor.index()
+    }
+
+    fn hwmon_path(&self) -> &Path {
+        self.sensor.hwmon_path()
+    }
+
+    fn alarm_active_low(&self) -> bool {
+        self.sensor.alarm_active_low()
+    }
+
+    fn set_alarm_polarity(&mut self, active_low: bool) {
+        self.sensor.set_alarm_polarity(active_low);
+    }
+
+    fn name(&self) -> String {
+        self.sensor.name()
+    }
+
+    fn read_raw(&self, sub_type: SensorSubFunctionType) -> Result<String> {
+        let raw = self.sensor.read_raw(sub_type)?;
+
+        if sub_type != SensorSubFunctionType::Input {
+            return Ok(raw);
+        }
+
+        let value = raw.parse::<i64>().map_err(UnitError::parsing)?;
+
+        Ok((self.transform)(value).to_string())
+    }
+}
+
+impl<S: CurrentSensor> CurrentSensor for TransformedSensor<S> {}
+
+impl<S: EnergySensor> EnergySensor for TransformedSensor<S> {}
+
+impl<S: FanSensor> FanSensor for TransformedSensor<S> {}
+
+impl<S: HumiditySensor> HumiditySensor for TransformedSensor<S> {}
+
+impl<S: IntrusionSensor> IntrusionSensor for TransformedSensor<S> {}
+
+impl<S: PowerSensor> PowerSensor for TransformedSensor<S> {}
+
+impl<S: PwmSensor> PwmSensor for TransformedSensor<S> {}
+
+impl<S: TempSensor> TempSensor for TransformedSensor<S> {}
+
+impl<S: VoltageSensor> VoltageSensor for TransformedSensor<S> {}
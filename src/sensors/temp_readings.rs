@@ -0,0 +1,49 @@
+//! Module containing the independently read subfunctions of a temp sensor.
+
+use crate::sensors::Error;
+use crate::units::{TempType, Temperature};
+
+/// The results of reading every subfunction of a temp sensor independently.
+/// Every field contains either the read value or the error that occurred while reading it,
+/// which makes this useful for diagnostic dumps.
+#[derive(Debug)]
+pub struct TempReadings {
+    /// The input subfunction.
+    pub input: Result<Temperature, Error>,
+    /// The min subfunction.
+    pub min: Result<Temperature, Error>,
+    /// The max subfunction.
+    pub max: Result<Temperature, Error>,
+    /// The crit subfunction.
+    pub crit: Result<Temperature, Error>,
+    /// The lcrit subfunction.
+    pub lcrit: Result<Temperature, Error>,
+    /// The emergency subfunction.
+    pub emergency: Result<Temperature, Error>,
+    /// The min_hyst subfunction.
+    pub min_hyst: Result<Temperature, Error>,
+    /// The max_hyst subfunction.
+    pub max_hyst: Result<Temperature, Error>,
+    /// The crit_hyst subfunction.
+    pub crit_hyst: Result<Temperature, Error>,
+    /// The offset subfunction.
+    pub offset: Result<Temperature, Error>,
+    /// The type subfunction.
+    pub temp_type: Result<TempType, Error>,
+    /// The enable subfunction.
+    pub enable: Result<bool, Error>,
+    /// The fault subfunction.
+    pub faulty: Result<bool, Error>,
+    /// The alarm subfunction.
+    pub alarm: Result<bool, Error>,
+    /// The min_alarm subfunction.
+    pub min_alarm: Result<bool, Error>,
+    /// The max_alarm subfunction.
+    pub max_alarm: Result<bool, Error>,
+    /// The crit_alarm subfunction.
+    pub crit_alarm: Result<bool, Error>,
+    /// The lcrit_alarm subfunction.
+    pub lcrit_alarm: Result<bool, Error>,
+    /// The emergency_alarm subfunction.
+    pub emergency_alarm: Result<bool, Error>,
+}
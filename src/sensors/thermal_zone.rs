@@ -0,0 +1,31 @@
+//! Helpers for finding the thermal zone a hwmon belongs to.
+
+use std::path::{Path, PathBuf};
+
+/// The trip point type of a thermal zone that marks its critical temperature.
+pub(crate) const CRITICAL_TRIP_TYPE: &str = "critical";
+
+/// Returns the path of the thermal zone the hwmon at `hwmon_path` belongs to if there is one.
+/// The zone is either the hwmon's device or the directory the hwmon was registered under.
+pub(crate) fn thermal_zone_path(hwmon_path: &Path) -> Option<PathBuf> {
+    let candidates = [
+        hwmon_path.join("device").canonicalize().ok(),
+        hwmon_path
+            .canonicalize()
+            .ok()
+            .and_then(|path| path.parent().map(Path::to_path_buf)),
+    ];
+
+    candidates.into_iter().flatten().find(|path| {
+        path.file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with("thermal_zone"))
+    })
+}
+
+/// Returns the paths of the type and temp files of the thermal zone's trip point with the given index.
+pub(crate) fn trip_point_paths(zone: &Path, index: usize) -> (PathBuf, PathBuf) {
+    (
+        zone.join(format!("trip_point_{}_type", index)),
+        zone.join(format!("trip_point_{}_temp", index)),
+    )
+}
@@ -23,12 +23,12 @@ impl VirtualHwmonBuilder {
 
         File::create(path.join("name"))
             .unwrap()
-            .write(name.as_ref())
+            .write_all(name.as_ref())
             .unwrap();
 
         File::create(path.join("update_interval"))
             .unwrap()
-            .write("1000".as_bytes())
+            .write_all("1000".as_bytes())
             .unwrap();
 
         VirtualHwmonBuilder {
@@ -45,7 +45,7 @@ impl VirtualHwmonBuilder {
             .truncate(true)
             .open(self.path().join(format!("temp{}_input", index)))
             .unwrap()
-            .write(value.to_string().as_bytes())
+            .write_all(value.to_string().as_bytes())
             .unwrap();
 
         OpenOptions::new()
@@ -55,7 +55,7 @@ impl VirtualHwmonBuilder {
             .truncate(true)
             .open(self.path().join(format!("temp{}_enable", index)))
             .unwrap()
-            .write(b"1\n")
+            .write_all(b"1\n")
             .unwrap();
 
         OpenOptions::new()
@@ -65,7 +65,7 @@ impl VirtualHwmonBuilder {
             .truncate(true)
             .open(self.path().join(format!("temp{}_label", index)))
             .unwrap()
-            .write(label.as_ref().as_bytes())
+            .write_all(label.as_ref().as_bytes())
             .unwrap();
 
         self
@@ -79,7 +79,7 @@ impl VirtualHwmonBuilder {
             .truncate(true)
             .open(self.path().join(format!("fan{}_input", index)))
             .unwrap()
-            .write(value.to_string().as_bytes())
+            .write_all(value.to_string().as_bytes())
             .unwrap();
 
         OpenOptions::new()
@@ -89,7 +89,63 @@ impl VirtualHwmonBuilder {
             .truncate(true)
             .open(self.path().join(format!("fan{}_enable", index)))
             .unwrap()
-            .write(b"1\n")
+            .write_all(b"1\n")
+            .unwrap();
+
+        self
+    }
+
+    pub fn add_energy(self, index: u16, value: u32) -> VirtualHwmonBuilder {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(self.path().join(format!("energy{}_input", index)))
+            .unwrap()
+            .write_all(value.to_string().as_bytes())
+            .unwrap();
+
+        self
+    }
+
+    pub fn add_power(self, index: u16, value: u32) -> VirtualHwmonBuilder {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(self.path().join(format!("power{}_input", index)))
+            .unwrap()
+            .write_all(value.to_string().as_bytes())
+            .unwrap();
+
+        self
+    }
+
+    pub fn add_voltage(self, index: u16, value: i32) -> VirtualHwmonBuilder {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(self.path().join(format!("in{}_input", index)))
+            .unwrap()
+            .write_all(value.to_string().as_bytes())
+            .unwrap();
+
+        self
+    }
+
+    pub fn add_current(self, index: u16, value: i32) -> VirtualHwmonBuilder {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(self.path().join(format!("curr{}_input", index)))
+            .unwrap()
+            .write_all(value.to_string().as_bytes())
             .unwrap();
 
         self
@@ -106,9 +162,9 @@ impl VirtualHwmonBuilder {
             .write(true)
             .create(true)
             .truncate(true)
-            .open(self.path().join(&format!("pwm{}", index)))
+            .open(self.path().join(format!("pwm{}", index)))
             .unwrap()
-            .write(b"0\n")
+            .write_all(b"0\n")
             .unwrap();
         if create_enable_file {
             OpenOptions::new()
@@ -116,9 +172,9 @@ impl VirtualHwmonBuilder {
                 .write(true)
                 .create(true)
                 .truncate(true)
-                .open(self.path().join(&format!("pwm{}_enable", index)))
+                .open(self.path().join(format!("pwm{}_enable", index)))
                 .unwrap()
-                .write(b"2\n")
+                .write_all(b"2\n")
                 .unwrap();
         }
         if create_mode_file {
@@ -127,15 +183,39 @@ impl VirtualHwmonBuilder {
                 .write(true)
                 .create(true)
                 .truncate(true)
-                .open(self.path().join(&format!("pwm{}_mode", index)))
+                .open(self.path().join(format!("pwm{}_mode", index)))
                 .unwrap()
-                .write(b"1\n")
+                .write_all(b"1\n")
                 .unwrap();
         }
 
         self.add_fan(index, 1000)
     }
 
+    pub fn add_device(self, subsystem: &str, device_name: &str) -> VirtualHwmonBuilder {
+        let device_path = self.root.join("devices").join(device_name);
+        let subsystem_path = self.root.join("bus").join(subsystem);
+
+        fs::create_dir_all(&device_path).unwrap();
+        fs::create_dir_all(&subsystem_path).unwrap();
+
+        if !device_path.join("subsystem").exists() {
+            std::os::unix::fs::symlink(subsystem_path, device_path.join("subsystem")).unwrap();
+        }
+        std::os::unix::fs::symlink(device_path, self.path().join("device")).unwrap();
+
+        self
+    }
+
+    pub fn add_driver(self, driver_name: &str) -> VirtualHwmonBuilder {
+        let driver_path = self.root.join("drivers").join(driver_name);
+
+        fs::create_dir_all(&driver_path).unwrap();
+        std::os::unix::fs::symlink(driver_path, self.path().join("device").join("driver")).unwrap();
+
+        self
+    }
+
     pub fn path(&self) -> PathBuf {
         self.root.join(format!("hwmon{}", self.index))
     }
@@ -270,3 +350,40 @@ async fn async_test_parse() {
     assert_eq!(hwmon0.temps().get(&2u16).unwrap().name().await, "temp2");
     assert_eq!(hwmon1.temps().get(&1u16).unwrap().name().await, "temp1");
 }
+
+#[test]
+#[cfg(feature = "sync")]
+fn test_unified_error() {
+    use crate::sensors::sync_sensors::temp::TempSensor;
+    use crate::units::{Raw, Temperature};
+
+    fn parse_temp(path: &Path, raw: &str) -> Result<Temperature, crate::Error> {
+        let hwmons = crate::hwmon::sync_hwmon::Hwmons::parse_path(path)?;
+        let hwmon = hwmons.hwmon_by_index(0).unwrap();
+
+        hwmon.temp(1).unwrap().read_input()?;
+
+        Ok(Temperature::from_raw(raw)?)
+    }
+
+    let test_dir = TempDir::new().unwrap();
+
+    assert!(matches!(
+        parse_temp(&test_dir.path().join("missing"), "40000"),
+        Err(crate::Error::Parsing { .. })
+    ));
+
+    VirtualHwmonBuilder::create(test_dir.path(), 0, "system").add_temp(1, 40000, "temp1");
+
+    assert!(matches!(
+        parse_temp(test_dir.path(), "invalid"),
+        Err(crate::Error::Unit { .. })
+    ));
+
+    fs::write(test_dir.path().join("hwmon0/temp1_fault"), "1").unwrap();
+
+    assert!(matches!(
+        parse_temp(test_dir.path(), "40000"),
+        Err(crate::Error::Sensor { .. })
+    ));
+}
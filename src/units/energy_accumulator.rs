@@ -0,0 +1,92 @@
+use crate::units::{Energy, Power, RawInteger};
+
+use std::time::Duration;
+
+/// Accumulator for energy totals over long periods of time.
+///
+/// Sysfs energy and power values only span a limited range, so long running totals can overflow
+/// them quickly. This accumulator keeps its total in microjoules as a u64 instead.
+/// If even that overflows, the total saturates and [`overflowed`](Self::overflowed) returns true.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EnergyAccumulator {
+    micro_joules: u64,
+    overflowed: bool,
+}
+
+impl EnergyAccumulator {
+    /// Creates a new empty `EnergyAccumulator`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given energy to the total.
+    /// Negative energies, which can only occur with the `uom_units` feature, are ignored.
+    pub fn add_energy(&mut self, energy: Energy) {
+        self.add_micro_joules(non_negative(energy.to_raw_integer()));
+    }
+
+    /// Adds the energy used by drawing the given power for the given duration to the total.
+    /// Negative powers, which can only occur with the `uom_units` feature, are ignored.
+    pub fn add_power(&mut self, power: Power, duration: Duration) {
+        let micro_watts = non_negative(power.to_raw_integer());
+
+        self.add_micro_joules(micro_watts * duration.as_nanos() / 1_000_000_000);
+    }
+
+    /// Returns the accumulated total in microjoules.
+    pub fn as_micro_joules(&self) -> u64 {
+        self.micro_joules
+    }
+
+    /// Returns the accumulated total in joules.
+    pub fn as_joules(&self) -> f64 {
+        self.micro_joules as f64 / 1_000_000.0
+    }
+
+    /// Returns whether the total overflowed and got saturated at some point.
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    fn add_micro_joules(&mut self, micro_joules: u128) {
+        let sum = u128::from(self.micro_joules) + micro_joules;
+
+        match u64::try_from(sum) {
+            Ok(sum) => self.micro_joules = sum,
+            Err(_) => {
+                self.micro_joules = u64::MAX;
+                self.overflowed = true;
+            }
+        }
+    }
+}
+
+fn non_negative(value: i64) -> u128 {
+    u128::from(value.max(0).unsigned_abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::units::Raw;
+
+    #[test]
+    fn test_accumulate_past_u32() {
+        let mut accumulator = EnergyAccumulator::new();
+
+        accumulator.add_energy(Energy::from_raw("4000000000").unwrap());
+        accumulator.add_energy(Energy::from_raw("4000000000").unwrap());
+        accumulator.add_power(
+            Power::from_raw("1000000").unwrap(),
+            Duration::from_secs(3600),
+        );
+
+        assert_eq!(11_600_000_000, accumulator.as_micro_joules());
+        assert!(!accumulator.overflowed());
+
+        accumulator.add_power(Power::from_raw("4000000000").unwrap(), Duration::MAX);
+
+        assert_eq!(u64::MAX, accumulator.as_micro_joules());
+        assert!(accumulator.overflowed());
+    }
+}
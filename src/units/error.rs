@@ -28,6 +28,12 @@ pub enum Error {
         value: f64,
     },
 
+    /// A human entered string could not be parsed.
+    UnparseableString {
+        /// The string that cannot be parsed.
+        string: String,
+    },
+
     /// Error parsing string to float.
     #[cfg(feature = "uom_units")]
     ParsingFloat { source: ParseFloatError },
@@ -48,6 +54,13 @@ impl Error {
         }
     }
 
+    #[cfg_attr(feature = "uom_units", allow(dead_code))]
+    pub(crate) fn unparseable_string(string: impl Into<String>) -> Self {
+        Self::UnparseableString {
+            string: string.into(),
+        }
+    }
+
     #[cfg(feature = "uom_units")]
     pub(crate) fn parsing_float(source: ParseFloatError) -> Self {
         Self::ParsingFloat { source }
@@ -60,6 +73,7 @@ impl StdError for Error {
             Error::RawConversion { .. } => None,
             Error::Parsing { source } => Some(source),
             Error::InvalidValue { .. } => None,
+            Error::UnparseableString { .. } => None,
             #[cfg(feature = "uom_units")]
             Error::ParsingFloat { source } => Some(source),
         }
@@ -76,6 +90,13 @@ impl Display for Error {
             Self::InvalidValue { value } => {
                 write!(f, "Invalid value to convert: {}", value)
             }
+            Self::UnparseableString { string } => {
+                write!(
+                    f,
+                    "Unable to parse '{}' as a number with an optional unit",
+                    string
+                )
+            }
             #[cfg(feature = "uom_units")]
             Self::ParsingFloat { .. } => {
                 write!(f, "Error parsing string to float")
@@ -1,6 +1,15 @@
-use crate::units::{Error as UnitError, Raw, Result as UnitResult};
+use crate::units::{AngularVelocity, Error as UnitError, Raw, Result as UnitResult};
 use std::borrow::Cow;
 
+/// Clock frequency of the classic fan tachometer counters in Hz multiplied by 60 seconds.
+const FAN_CLOCK: u32 = 1_350_000;
+
+/// Largest count the classic 8 bit fan tachometer counters can hold.
+const FAN_MAX_COUNT: u32 = 255;
+
+/// Largest fan divisor most chips accept.
+const MAX_DIVISOR: u32 = 8;
+
 /// Struct representing a fan divisor. Fan divisors can only be powers of two.
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Hash, Ord)]
 pub struct FanDivisor(u32);
@@ -18,10 +27,55 @@ impl FanDivisor {
         Ok(FanDivisor(value))
     }
 
+    /// Returns a FanDivisor created from a given value, if most chips accept it.
+    /// This is the case for the powers of two from 1 to 8. Chips reject other values with `EINVAL`.
+    /// Returns an error for any other value.
+    pub fn try_new(value: u8) -> UnitResult<FanDivisor> {
+        let value = u32::from(value);
+
+        if !value.is_power_of_two() || value > MAX_DIVISOR {
+            return Err(UnitError::invalid_value(value));
+        }
+
+        Ok(FanDivisor(value))
+    }
+
     /// Returns the value stored in this `FanDivisor`.
     pub fn as_value(self) -> u32 {
         self.0
     }
+
+    /// Returns the value stored in this `FanDivisor` as a u8.
+    /// Values too large for a u8 are saturated to `u8::MAX`.
+    pub fn as_u8(self) -> u8 {
+        u8::try_from(self.0).unwrap_or(u8::MAX)
+    }
+
+    /// Returns the highest fan speed that can be measured with this divisor by chips using the
+    /// classic 8 bit tachometer counter clocked at 22.5kHz.
+    /// This is the speed at which the counter reads 1 and thus the resolution limit of the divisor.
+    /// Faster fans alias to lower counts, so a reading near this value suggests lowering the divisor.
+    pub fn max_measurable_rpm(self) -> AngularVelocity {
+        Self::rpm(FAN_CLOCK / self.0.max(1))
+    }
+
+    /// Returns the lowest fan speed that can be measured with this divisor by chips using the
+    /// classic 8 bit tachometer counter clocked at 22.5kHz.
+    /// Slower fans overflow the counter and are read as 0.
+    /// Increasing the divisor lowers this limit at the cost of resolution.
+    pub fn min_measurable_rpm(self) -> AngularVelocity {
+        Self::rpm(FAN_CLOCK.div_ceil(FAN_MAX_COUNT * self.0.max(1)))
+    }
+
+    #[cfg(not(feature = "uom_units"))]
+    fn rpm(rpm: u32) -> AngularVelocity {
+        AngularVelocity::from_rpm(rpm)
+    }
+
+    #[cfg(feature = "uom_units")]
+    fn rpm(rpm: u32) -> AngularVelocity {
+        AngularVelocity::new::<uom::si::angular_velocity::revolution_per_minute>(f64::from(rpm))
+    }
 }
 
 impl Raw for FanDivisor {
@@ -32,7 +86,7 @@ impl Raw for FanDivisor {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.0.to_string())
     }
 }
@@ -48,4 +102,41 @@ mod tests {
         assert!(FanDivisor::try_from_value(2u32).is_ok());
         assert!(FanDivisor::try_from_value(3u32).is_err());
     }
+
+    #[test]
+    fn test_try_new() {
+        for value in [1, 2, 4, 8] {
+            assert_eq!(
+                u32::from(value),
+                FanDivisor::try_new(value).unwrap().as_value()
+            );
+        }
+
+        for value in [0, 3, 16] {
+            assert!(FanDivisor::try_new(value).is_err());
+        }
+    }
+
+    #[test]
+    fn test_measurable_rpm() {
+        for (divisor, min, max) in [
+            (1u32, "5295", "1350000"),
+            (2, "2648", "675000"),
+            (4, "1324", "337500"),
+            (8, "662", "168750"),
+        ] {
+            let divisor = FanDivisor::try_from_value(divisor).unwrap();
+
+            assert_eq!(
+                AngularVelocity::from_raw(min).unwrap(),
+                divisor.min_measurable_rpm()
+            );
+            assert_eq!(
+                AngularVelocity::from_raw(max).unwrap(),
+                divisor.max_measurable_rpm()
+            );
+        }
+
+        assert_eq!(8, FanDivisor::try_from_value(8u32).unwrap().as_u8());
+    }
 }
@@ -1,5 +1,6 @@
 //! Units used in this library.
 
+mod energy_accumulator;
 mod error;
 mod fan_divisor;
 mod pwm;
@@ -11,6 +12,7 @@ mod native;
 #[cfg(feature = "uom_units")]
 mod uom;
 
+pub use energy_accumulator::EnergyAccumulator;
 pub use error::Error;
 pub use fan_divisor::FanDivisor;
 pub use pwm::*;
@@ -27,13 +29,55 @@ pub(crate) use error::Result;
 use std::borrow::Cow;
 use std::time::Duration;
 
+/// Parses a human entered temperature like "42C", "42.5 °C" or "108F" into degrees celsius.
+/// Values without a unit suffix are interpreted as degrees celsius.
+pub(crate) fn parse_lenient_celsius(s: &str) -> Result<f64> {
+    let trimmed = s.trim();
+
+    let (number, fahrenheit) = if let Some(number) = trimmed
+        .strip_suffix(['F', 'f'])
+        .map(|n| n.trim_end().trim_end_matches('°'))
+    {
+        (number, true)
+    } else if let Some(number) = trimmed
+        .strip_suffix(['C', 'c'])
+        .map(|n| n.trim_end().trim_end_matches('°'))
+    {
+        (number, false)
+    } else {
+        (trimmed, false)
+    };
+
+    let degrees = number
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| Error::unparseable_string(s))?;
+
+    if fahrenheit {
+        Ok((degrees - 32.0) / 1.8)
+    } else {
+        Ok(degrees)
+    }
+}
+
 /// Trait that needs to be implemented by all types that raw sensor strings should be converted into.
 pub trait Raw: Sized {
     /// Converts a raw sensor string into a usable type.
     fn from_raw(raw: &str) -> Result<Self>;
 
     /// Converts self into a writeable raw sensor string.
-    fn to_raw(&self) -> Cow<str>;
+    fn to_raw(&self) -> Cow<'_, str>;
+}
+
+/// Trait for units whose raw sensor strings are integers like millidegrees celsius or microwatts.
+/// It allows doing arithmetic on typed values without round-tripping through strings.
+pub(crate) trait RawInteger: Sized {
+    /// Returns the raw integer value of self.
+    fn to_raw_integer(&self) -> i64;
+
+    /// Converts a raw integer value into a usable type.
+    /// Returns an error, if the value is out of bounds for the type.
+    fn try_from_raw_integer(raw: i64) -> Result<Self>;
 }
 
 impl Raw for bool {
@@ -45,7 +89,7 @@ impl Raw for bool {
         }
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         match self {
             true => Cow::Borrowed("1"),
             false => Cow::Borrowed("0"),
@@ -58,7 +102,7 @@ impl Raw for String {
         Ok(raw.trim().to_string())
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Borrowed(self.as_str())
     }
 }
@@ -71,7 +115,7 @@ impl Raw for Duration {
             .map_err(Error::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.as_millis().to_string())
     }
 }
@@ -1,4 +1,4 @@
-use crate::units::{Error as UnitError, Raw, Result as UnitResult};
+use crate::units::{Error as UnitError, Raw, RawInteger, Result as UnitResult};
 
 use std::borrow::Cow;
 use std::fmt;
@@ -20,6 +20,16 @@ impl AngularVelocity {
     }
 }
 
+#[cfg(feature = "uom_convert")]
+impl AngularVelocity {
+    /// Converts this angular velocity into the equivalent quantity of the `uom` crate.
+    pub fn to_uom(self) -> uom::si::f64::AngularVelocity {
+        uom::si::f64::AngularVelocity::new::<uom::si::angular_velocity::revolution_per_minute>(
+            f64::from(self.as_rpm()),
+        )
+    }
+}
+
 impl Raw for AngularVelocity {
     fn from_raw(raw: &str) -> UnitResult<Self> {
         raw.trim()
@@ -28,11 +38,23 @@ impl Raw for AngularVelocity {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.as_rpm().to_string())
     }
 }
 
+impl RawInteger for AngularVelocity {
+    fn to_raw_integer(&self) -> i64 {
+        i64::from(self.0)
+    }
+
+    fn try_from_raw_integer(raw: i64) -> UnitResult<Self> {
+        u32::try_from(raw)
+            .map(AngularVelocity)
+            .map_err(|_| UnitError::invalid_value(raw as f64))
+    }
+}
+
 impl fmt::Display for AngularVelocity {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}rpm", self.as_rpm())
@@ -1,4 +1,4 @@
-use crate::units::{Error as UnitError, Raw, Result as UnitResult};
+use crate::units::{Error as UnitError, Raw, RawInteger, Result as UnitResult};
 
 use std::borrow::Cow;
 use std::fmt;
@@ -39,6 +39,16 @@ impl Current {
     }
 }
 
+#[cfg(feature = "uom_convert")]
+impl Current {
+    /// Converts this current into the equivalent quantity of the `uom` crate.
+    pub fn to_uom(self) -> uom::si::f64::ElectricCurrent {
+        uom::si::f64::ElectricCurrent::new::<uom::si::electric_current::milliampere>(f64::from(
+            self.as_milli_amperes(),
+        ))
+    }
+}
+
 impl Raw for Current {
     fn from_raw(raw: &str) -> UnitResult<Self> {
         raw.trim()
@@ -47,11 +57,23 @@ impl Raw for Current {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.0.to_string())
     }
 }
 
+impl RawInteger for Current {
+    fn to_raw_integer(&self) -> i64 {
+        i64::from(self.0)
+    }
+
+    fn try_from_raw_integer(raw: i64) -> UnitResult<Self> {
+        i32::try_from(raw)
+            .map(Current)
+            .map_err(|_| UnitError::invalid_value(raw as f64))
+    }
+}
+
 impl fmt::Display for Current {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}A", self.as_amperes())
@@ -1,4 +1,4 @@
-use crate::units::{Error as UnitError, Raw, Result as UnitResult};
+use crate::units::{Error as UnitError, Raw, RawInteger, Result as UnitResult};
 
 use std::borrow::Cow;
 use std::fmt;
@@ -35,6 +35,19 @@ impl Energy {
     pub fn as_joules(self) -> f64 {
         f64::from(self.0) / 1_000_000.0
     }
+
+    /// Adds two energies and returns `None`, if the result overflows.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Energy)
+    }
+}
+
+#[cfg(feature = "uom_convert")]
+impl Energy {
+    /// Converts this energy into the equivalent quantity of the `uom` crate.
+    pub fn to_uom(self) -> uom::si::f64::Energy {
+        uom::si::f64::Energy::new::<uom::si::energy::microjoule>(f64::from(self.as_micro_joules()))
+    }
 }
 
 impl Raw for Energy {
@@ -45,11 +58,23 @@ impl Raw for Energy {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.as_micro_joules().to_string())
     }
 }
 
+impl RawInteger for Energy {
+    fn to_raw_integer(&self) -> i64 {
+        i64::from(self.0)
+    }
+
+    fn try_from_raw_integer(raw: i64) -> UnitResult<Self> {
+        u32::try_from(raw)
+            .map(Energy)
+            .map_err(|_| UnitError::invalid_value(raw as f64))
+    }
+}
+
 impl fmt::Display for Energy {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}J", self.as_joules())
@@ -84,6 +109,17 @@ impl<T: Into<u32>> Div<T> for Energy {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_checked_add() {
+        let energy = Energy::from_micro_joules(u32::MAX - 1);
+
+        assert_eq!(
+            Some(Energy::from_micro_joules(u32::MAX)),
+            energy.checked_add(Energy::from_micro_joules(1u32))
+        );
+        assert_eq!(None, energy.checked_add(Energy::from_micro_joules(2u32)));
+    }
+
     #[test]
     fn test_out_of_bounds() {
         assert!(Energy::try_from_joules(f64::INFINITY).is_err());
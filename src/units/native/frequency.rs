@@ -20,6 +20,14 @@ impl Frequency {
     }
 }
 
+#[cfg(feature = "uom_convert")]
+impl Frequency {
+    /// Converts this frequency into the equivalent quantity of the `uom` crate.
+    pub fn to_uom(self) -> uom::si::f64::Frequency {
+        uom::si::f64::Frequency::new::<uom::si::frequency::hertz>(f64::from(self.as_hertz()))
+    }
+}
+
 impl Raw for Frequency {
     fn from_raw(raw: &str) -> UnitResult<Self> {
         raw.trim()
@@ -28,7 +36,7 @@ impl Raw for Frequency {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.as_hertz().to_string())
     }
 }
@@ -1,4 +1,4 @@
-use crate::units::{Error as UnitError, Raw, Result as UnitResult};
+use crate::units::{Error as UnitError, Raw, RawInteger, Result as UnitResult};
 
 use std::borrow::Cow;
 use std::fmt;
@@ -37,6 +37,14 @@ impl Power {
     }
 }
 
+#[cfg(feature = "uom_convert")]
+impl Power {
+    /// Converts this power into the equivalent quantity of the `uom` crate.
+    pub fn to_uom(self) -> uom::si::f64::Power {
+        uom::si::f64::Power::new::<uom::si::power::microwatt>(f64::from(self.as_microwatts()))
+    }
+}
+
 impl Raw for Power {
     fn from_raw(raw: &str) -> UnitResult<Self> {
         raw.trim()
@@ -45,11 +53,23 @@ impl Raw for Power {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.as_microwatts().to_string())
     }
 }
 
+impl RawInteger for Power {
+    fn to_raw_integer(&self) -> i64 {
+        i64::from(self.0)
+    }
+
+    fn try_from_raw_integer(raw: i64) -> UnitResult<Self> {
+        u32::try_from(raw)
+            .map(Power)
+            .map_err(|_| UnitError::invalid_value(raw as f64))
+    }
+}
+
 impl fmt::Display for Power {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}W", self.as_watts())
@@ -25,6 +25,14 @@ impl Ratio {
     }
 }
 
+#[cfg(feature = "uom_convert")]
+impl Ratio {
+    /// Converts this ratio into the equivalent quantity of the `uom` crate.
+    pub fn to_uom(self) -> uom::si::f64::Ratio {
+        uom::si::f64::Ratio::new::<uom::si::ratio::percent>(self.as_percent())
+    }
+}
+
 impl Raw for Ratio {
     fn from_raw(raw: &str) -> UnitResult<Self> {
         raw.trim()
@@ -33,7 +41,7 @@ impl Raw for Ratio {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.as_milli_percent().to_string())
     }
 }
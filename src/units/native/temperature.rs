@@ -1,4 +1,4 @@
-use crate::units::{Error as UnitError, Raw, Result as UnitResult};
+use crate::units::{Error as UnitError, Raw, RawInteger, Result as UnitResult};
 
 use std::borrow::Cow;
 use std::fmt;
@@ -34,6 +34,13 @@ impl Temperature {
         Self::try_from_degrees_celsius((degrees.into() - 32.0) / 1.8)
     }
 
+    /// Parses a human entered temperature like "42C", "42.5 °C" or "108F".
+    /// Values without a unit suffix are interpreted as degrees celsius.
+    /// Returns an error if the string can't be parsed or the value is out of bounds.
+    pub fn from_str_lenient(s: &str) -> UnitResult<Self> {
+        Self::try_from_degrees_celsius(crate::units::parse_lenient_celsius(s)?)
+    }
+
     /// Returns the struct's value as degrees celsius.
     pub fn as_degrees_celsius(self) -> f64 {
         f64::from(self.0) / 1_000.0
@@ -50,6 +57,16 @@ impl Temperature {
     }
 }
 
+#[cfg(feature = "uom_convert")]
+impl Temperature {
+    /// Converts this temperature into the equivalent quantity of the `uom` crate.
+    pub fn to_uom(self) -> uom::si::f64::ThermodynamicTemperature {
+        uom::si::f64::ThermodynamicTemperature::new::<
+            uom::si::thermodynamic_temperature::degree_celsius,
+        >(self.as_degrees_celsius())
+    }
+}
+
 impl Raw for Temperature {
     fn from_raw(raw: &str) -> UnitResult<Self> {
         raw.trim()
@@ -58,11 +75,23 @@ impl Raw for Temperature {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.as_millidegrees_celsius().to_string())
     }
 }
 
+impl RawInteger for Temperature {
+    fn to_raw_integer(&self) -> i64 {
+        i64::from(self.0)
+    }
+
+    fn try_from_raw_integer(raw: i64) -> UnitResult<Self> {
+        i32::try_from(raw)
+            .map(Temperature)
+            .map_err(|_| UnitError::invalid_value(raw as f64))
+    }
+}
+
 impl fmt::Display for Temperature {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}°C", self.as_degrees_celsius())
@@ -110,4 +139,41 @@ mod tests {
         assert!(Temperature::try_from_degrees_celsius(i32::MIN / 1_000).is_ok());
         assert!(Temperature::try_from_degrees_celsius(i32::MIN / 1_000 - 1).is_err());
     }
+
+    #[cfg(feature = "uom_convert")]
+    #[test]
+    fn test_to_uom() {
+        use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+
+        let temp = Temperature::from_millidegrees_celsius(42500).to_uom();
+
+        assert!((temp.get::<degree_celsius>() - 42.5).abs() < 0.001);
+        assert!((temp.get::<kelvin>() - 315.65).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_from_str_lenient() {
+        assert_eq!(
+            Temperature::from_millidegrees_celsius(42000),
+            Temperature::from_str_lenient("42C").unwrap()
+        );
+        assert_eq!(
+            Temperature::from_millidegrees_celsius(42500),
+            Temperature::from_str_lenient("42.5 °C").unwrap()
+        );
+        assert_eq!(
+            Temperature::from_millidegrees_celsius(-5000),
+            Temperature::from_str_lenient(" -5 ").unwrap()
+        );
+        assert!(
+            (Temperature::from_str_lenient("108F")
+                .unwrap()
+                .as_degrees_fahrenheit()
+                - 108.0)
+                .abs()
+                < 0.01
+        );
+        assert!(Temperature::from_str_lenient("42K").is_err());
+        assert!(Temperature::from_str_lenient("").is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::units::{Error as UnitError, Raw, Result as UnitResult};
+use crate::units::{Error as UnitError, Raw, RawInteger, Result as UnitResult};
 
 use std::borrow::Cow;
 use std::fmt;
@@ -40,6 +40,16 @@ impl Voltage {
     }
 }
 
+#[cfg(feature = "uom_convert")]
+impl Voltage {
+    /// Converts this voltage into the equivalent quantity of the `uom` crate.
+    pub fn to_uom(self) -> uom::si::f64::ElectricPotential {
+        uom::si::f64::ElectricPotential::new::<uom::si::electric_potential::millivolt>(f64::from(
+            self.as_milli_volts(),
+        ))
+    }
+}
+
 impl Raw for Voltage {
     fn from_raw(raw: &str) -> UnitResult<Self> {
         raw.trim()
@@ -48,11 +58,23 @@ impl Raw for Voltage {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.0.to_string())
     }
 }
 
+impl RawInteger for Voltage {
+    fn to_raw_integer(&self) -> i64 {
+        i64::from(self.0)
+    }
+
+    fn try_from_raw_integer(raw: i64) -> UnitResult<Self> {
+        i32::try_from(raw)
+            .map(Voltage)
+            .map_err(|_| UnitError::invalid_value(raw as f64))
+    }
+}
+
 impl fmt::Display for Voltage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}V", self.as_volts())
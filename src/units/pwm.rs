@@ -1,6 +1,7 @@
 use crate::units::{Error as UnitError, Raw, Result as UnitResult};
 use std::borrow::Cow;
 use std::fmt;
+use std::str::FromStr;
 
 /// Struct that represents a pwm value between 0 and 255.
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Hash, Ord)]
@@ -63,7 +64,7 @@ impl Raw for Pwm {
             .map_err(UnitError::parsing)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(self.0.to_string())
     }
 }
@@ -74,6 +75,60 @@ impl fmt::Display for Pwm {
     }
 }
 
+/// Inclusive range of pwm values that can be iterated in steps, e.g. to sweep fan speeds while
+/// characterizing a fan.
+/// The end is always yielded, even if the last step is shorter than `step`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PwmRange {
+    /// The first value of the range.
+    pub start: Pwm,
+    /// The last value of the range.
+    pub end: Pwm,
+    /// The distance between two consecutive values. A step of 0 is treated as 1.
+    pub step: u8,
+}
+
+impl PwmRange {
+    /// Creates a new `PwmRange` from `start` to `end` inclusive with the given step.
+    /// The range is empty, if `start` is greater than `end`.
+    pub fn new(start: Pwm, end: Pwm, step: u8) -> Self {
+        Self { start, end, step }
+    }
+}
+
+impl IntoIterator for PwmRange {
+    type Item = Pwm;
+    type IntoIter = PwmRangeIter;
+
+    fn into_iter(self) -> PwmRangeIter {
+        PwmRangeIter {
+            next: (self.start <= self.end).then_some(self.start.0),
+            end: self.end.0,
+            step: self.step.max(1),
+        }
+    }
+}
+
+/// Iterator over the values of a `PwmRange`.
+#[derive(Debug, Clone, Copy)]
+pub struct PwmRangeIter {
+    next: Option<u8>,
+    end: u8,
+    step: u8,
+}
+
+impl Iterator for PwmRangeIter {
+    type Item = Pwm;
+
+    fn next(&mut self) -> Option<Pwm> {
+        let current = self.next?;
+
+        self.next = (current < self.end).then(|| current.saturating_add(self.step).min(self.end));
+
+        Some(Pwm(current))
+    }
+}
+
 /// Enum that represents the control states a pwm can be in.
 #[allow(missing_docs)]
 #[derive(Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
@@ -93,7 +148,7 @@ impl Raw for PwmEnable {
         }
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         match self {
             PwmEnable::FullSpeed => Cow::from("0"),
             PwmEnable::ManualControl => Cow::from("1"),
@@ -102,6 +157,31 @@ impl Raw for PwmEnable {
     }
 }
 
+impl fmt::Display for PwmEnable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PwmEnable::FullSpeed => write!(f, "Full speed"),
+            PwmEnable::ManualControl => write!(f, "Manual"),
+            PwmEnable::BiosControl => write!(f, "Automatic (BIOS)"),
+        }
+    }
+}
+
+impl FromStr for PwmEnable {
+    type Err = UnitError;
+
+    /// Parses the strings produced by `Display` as well as the numeric raw values.
+    fn from_str(s: &str) -> UnitResult<Self> {
+        match s.trim() {
+            "Full speed" => Ok(PwmEnable::FullSpeed),
+            "Manual" => Ok(PwmEnable::ManualControl),
+            "Automatic (BIOS)" => Ok(PwmEnable::BiosControl),
+            raw if raw.parse::<u8>().is_ok() => PwmEnable::from_raw(raw),
+            _ => Err(UnitError::unparseable_string(s)),
+        }
+    }
+}
+
 /// Enum that represents the modes by which a fan's speed can be regulated.
 #[allow(missing_docs)]
 #[derive(Debug, Default, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
@@ -122,7 +202,7 @@ impl Raw for PwmMode {
         }
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         match self {
             PwmMode::Dc => Cow::from("0"),
             PwmMode::Pwm => Cow::from("1"),
@@ -131,6 +211,31 @@ impl Raw for PwmMode {
     }
 }
 
+impl fmt::Display for PwmMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PwmMode::Dc => write!(f, "DC"),
+            PwmMode::Pwm => write!(f, "PWM"),
+            PwmMode::Automatic => write!(f, "Automatic"),
+        }
+    }
+}
+
+impl FromStr for PwmMode {
+    type Err = UnitError;
+
+    /// Parses the strings produced by `Display` as well as the numeric raw values.
+    fn from_str(s: &str) -> UnitResult<Self> {
+        match s.trim() {
+            "DC" => Ok(PwmMode::Dc),
+            "PWM" => Ok(PwmMode::Pwm),
+            "Automatic" => Ok(PwmMode::Automatic),
+            raw if raw.parse::<u8>().is_ok() => PwmMode::from_raw(raw),
+            _ => Err(UnitError::unparseable_string(s)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,4 +250,70 @@ mod tests {
         assert!(Pwm::try_from_percent(f64::INFINITY).is_err());
         assert!(Pwm::try_from_percent(f64::NAN).is_err());
     }
+
+    #[test]
+    fn test_pwm_range() {
+        let values: Vec<u8> = PwmRange::new(Pwm::OFF, Pwm::FULLSPEED, 64)
+            .into_iter()
+            .map(Pwm::as_u8)
+            .collect();
+        assert_eq!(vec![0, 64, 128, 192, 255], values);
+
+        let values: Vec<u8> = PwmRange::new(Pwm::from_u8(100), Pwm::from_u8(140), 20)
+            .into_iter()
+            .map(Pwm::as_u8)
+            .collect();
+        assert_eq!(vec![100, 120, 140], values);
+
+        assert_eq!(
+            17,
+            PwmRange::new(Pwm::OFF, Pwm::FULLSPEED, 16)
+                .into_iter()
+                .count()
+        );
+        assert_eq!(
+            1,
+            PwmRange::new(Pwm::HALFSPEED, Pwm::HALFSPEED, 16)
+                .into_iter()
+                .count()
+        );
+        assert_eq!(
+            0,
+            PwmRange::new(Pwm::FULLSPEED, Pwm::OFF, 16)
+                .into_iter()
+                .count()
+        );
+        assert_eq!(
+            256,
+            PwmRange::new(Pwm::OFF, Pwm::FULLSPEED, 0)
+                .into_iter()
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_pwm_enable_round_trip() {
+        for enable in [
+            PwmEnable::FullSpeed,
+            PwmEnable::ManualControl,
+            PwmEnable::BiosControl,
+        ] {
+            assert_eq!(enable, enable.to_string().parse().unwrap());
+            assert_eq!(enable, enable.to_raw().parse().unwrap());
+        }
+
+        assert_eq!("Automatic (BIOS)", PwmEnable::BiosControl.to_string());
+        assert!("foo".parse::<PwmEnable>().is_err());
+    }
+
+    #[test]
+    fn test_pwm_mode_round_trip() {
+        for mode in [PwmMode::Dc, PwmMode::Pwm, PwmMode::Automatic] {
+            assert_eq!(mode, mode.to_string().parse().unwrap());
+            assert_eq!(mode, mode.to_raw().parse().unwrap());
+        }
+
+        assert!("3".parse::<PwmMode>().is_err());
+        assert!("foo".parse::<PwmMode>().is_err());
+    }
 }
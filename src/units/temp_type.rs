@@ -1,6 +1,7 @@
 use crate::units::{Error as UnitError, Raw, Result as UnitResult};
 
 use std::borrow::Cow;
+use std::fmt;
 
 /// Enum that represents the different temp sensor types.
 #[allow(missing_docs)]
@@ -27,7 +28,7 @@ impl Raw for TempType {
         }
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         match self {
             TempType::CpuEmbeddedDiode => Cow::from("1"),
             TempType::Transistor => Cow::from("2"),
@@ -38,3 +39,70 @@ impl Raw for TempType {
         }
     }
 }
+
+impl TempType {
+    /// Returns the string `sensors(1)` displays for this temp type, e.g. "CPU diode".
+    pub fn as_sensors_str(&self) -> &'static str {
+        match self {
+            TempType::CpuEmbeddedDiode => "CPU diode",
+            TempType::Transistor => "transistor",
+            TempType::ThermalDiode => "thermal diode",
+            TempType::Thermistor => "thermistor",
+            TempType::AmdAmdsi => "AMD AMDSI",
+            TempType::IntelPeci => "Intel PECI",
+        }
+    }
+
+    /// Returns whether this temp type measures a semiconductor junction like a CPU or GPU die,
+    /// as opposed to a thermistor which usually measures ambient or board temperatures.
+    /// This lets UIs apply different scales, since e.g. 35°C means idle for a CPU diode but a
+    /// warm room for a thermistor.
+    pub fn is_cpu_like(&self) -> bool {
+        match self {
+            TempType::CpuEmbeddedDiode
+            | TempType::Transistor
+            | TempType::ThermalDiode
+            | TempType::AmdAmdsi
+            | TempType::IntelPeci => true,
+            TempType::Thermistor => false,
+        }
+    }
+}
+
+impl fmt::Display for TempType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_sensors_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sensors_str() {
+        for (raw, string) in [
+            ("1", "CPU diode"),
+            ("2", "transistor"),
+            ("3", "thermal diode"),
+            ("4", "thermistor"),
+            ("5", "AMD AMDSI"),
+            ("6", "Intel PECI"),
+        ] {
+            let temp_type = TempType::from_raw(raw).unwrap();
+
+            assert_eq!(string, temp_type.as_sensors_str());
+            assert_eq!(string, temp_type.to_string());
+        }
+    }
+
+    #[test]
+    fn test_is_cpu_like() {
+        assert!(TempType::CpuEmbeddedDiode.is_cpu_like());
+        assert!(TempType::Transistor.is_cpu_like());
+        assert!(TempType::ThermalDiode.is_cpu_like());
+        assert!(TempType::AmdAmdsi.is_cpu_like());
+        assert!(TempType::IntelPeci.is_cpu_like());
+        assert!(!TempType::Thermistor.is_cpu_like());
+    }
+}
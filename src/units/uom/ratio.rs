@@ -15,7 +15,7 @@ impl Raw for Ratio {
             .map_err(UnitError::parsing_float)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{:.0}", self.get::<Percent>().round()))
     }
 }
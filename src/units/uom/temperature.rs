@@ -1,4 +1,4 @@
-use crate::units::{Error as UnitError, Raw, Result as UnitResult};
+use crate::units::{Error as UnitError, Raw, RawInteger, Result as UnitResult};
 
 use std::borrow::Cow;
 
@@ -17,7 +17,7 @@ impl Raw for Temperature {
             .map_err(UnitError::parsing_float)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(format!(
             "{:.0}",
             self.get::<MilliKelvin>().round() - 273150.0
@@ -25,6 +25,16 @@ impl Raw for Temperature {
     }
 }
 
+impl RawInteger for Temperature {
+    fn to_raw_integer(&self) -> i64 {
+        self.get::<MilliKelvin>().round() as i64 - 273150
+    }
+
+    fn try_from_raw_integer(raw: i64) -> UnitResult<Self> {
+        Ok(Temperature::new::<MilliKelvin>(raw as f64 + 273150.0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::units::{Raw, Temperature};
@@ -1,4 +1,4 @@
-use crate::units::{Error as UnitError, Raw, Result as UnitResult};
+use crate::units::{Error as UnitError, Raw, RawInteger, Result as UnitResult};
 
 use std::borrow::Cow;
 
@@ -15,11 +15,21 @@ impl Raw for Voltage {
             .map_err(UnitError::parsing_float)
     }
 
-    fn to_raw(&self) -> Cow<str> {
+    fn to_raw(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{:.0}", self.get::<MilliVolt>().round()))
     }
 }
 
+impl RawInteger for Voltage {
+    fn to_raw_integer(&self) -> i64 {
+        self.get::<MilliVolt>().round() as i64
+    }
+
+    fn try_from_raw_integer(raw: i64) -> UnitResult<Self> {
+        Ok(Voltage::new::<MilliVolt>(raw as f64))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::MilliVolt;